@@ -0,0 +1,208 @@
+///
+/// Module to compose with Github Apps.
+/// Github documentation available at https://developer.github.com/v3/apps/.
+///
+use client::GithubClient;
+use error::Error;
+use hyper::method::Method;
+use openssl::crypto::hash::Type;
+use openssl::crypto::pkey::PKey;
+use openssl::crypto::rsa::RSA;
+use pagination::{ListOptions, Page};
+use rustc_serialize::base64::{ToBase64, URL_SAFE};
+use serde_json;
+use user::UserInfoStructure;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// URL to access the Github API for installations of the authenticated Github App
+const INSTALLATIONS_API_URL: &'static str = "app/installations";
+/// Header of every JWT created to authenticate as a Github App
+const JWT_HEADER: &'static str = r#"{"alg":"RS256","typ":"JWT"}"#;
+/// Lifetime of a JWT, in seconds (Github accepts up to 10 minutes)
+const JWT_LIFETIME: u64 = 9 * 60;
+/// Drift allowed between the local clock and the Github one, in seconds
+const CLOCK_DRIFT: u64 = 60;
+/// Installation tokens are refreshed when they expire in less than this delay, in seconds
+const TOKEN_REFRESH_MARGIN: u64 = 5 * 60;
+/// Lifetime of an installation token, in seconds, if Github did not give its expiration date
+const DEFAULT_TOKEN_LIFETIME: u64 = 60 * 60;
+
+/// A client to communicate with the Github API as a Github App.
+/// Requests sent by this client are authenticated with a JWT, whatever the installation
+/// configured in the Github client.
+pub struct AppClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+}
+
+impl<'a> AppClient<'a> {
+    /// Returns a client to communicate with the Github API as a Github App
+    ///
+    /// # Argument
+    ///
+    /// * `github_client` - The Github client, that must be authenticated with `Auth::App`
+    pub fn new(github_client: &'a GithubClient) -> Self {
+        AppClient { github_client: github_client }
+    }
+
+    /// Returns a page of the installations of the Github App
+    ///
+    /// # Argument
+    ///
+    /// * `options` - The pagination parameters
+    pub fn installations(&self, options: &ListOptions) -> Result<Page<Installation>, Error> {
+        self.github_client.get_app_page(INSTALLATIONS_API_URL, options)
+    }
+
+    /// Returns a new access token for a given installation of the Github App
+    ///
+    /// # Argument
+    ///
+    /// * `installation_id` - The identifier of the installation
+    pub fn create_installation_token(&self,
+                                     installation_id: usize)
+                                     -> Result<InstallationToken, Error> {
+        let url = format!("{}/{}/access_tokens", INSTALLATIONS_API_URL, installation_id);
+        let response = try!(self.github_client.process_app_request(Method::Post, &url, None));
+        match serde_json::from_str(&response) {
+            Ok(token) => Ok(token),
+            Err(error) => {
+                Err(Error::Deserialize {
+                    error: error,
+                    body: response,
+                })
+            }
+        }
+    }
+}
+
+/// Claims of the JWT used to authenticate as a Github App
+#[derive(Serialize)]
+struct JwtClaims {
+    /// Issued at
+    iat: u64,
+    /// Expiration time
+    exp: u64,
+    /// Issuer: the identifier of the Github App
+    iss: usize,
+}
+
+/// Returns a JWT, signed with RS256, to authenticate as a Github App
+///
+/// # Arguments
+///
+/// * `app_id` - The identifier of the Github App
+/// * `private_key` - The PEM encoded private key of the Github App
+pub fn create_jwt(app_id: usize, private_key: &[u8]) -> Result<String, Error> {
+    let key = match PKey::private_key_from_pem(&mut &private_key[..]) {
+        Ok(key) => key,
+        Err(error) => return Err(Error::Auth(format!("Invalid private key: {}", error))),
+    };
+    // RS256 needs an RSA key (signing with another type of key would panic)
+    if RSA::private_key_from_pem(&mut &private_key[..]).is_err() {
+        return Err(Error::Auth(String::from("The private key is not an RSA key")));
+    }
+    let now = now();
+    let claims = JwtClaims {
+        // Backdate the token, in case the local clock is ahead of the Github one
+        iat: now.saturating_sub(CLOCK_DRIFT),
+        exp: now + JWT_LIFETIME,
+        iss: app_id,
+    };
+    let claims = match serde_json::to_string(&claims) {
+        Ok(claims) => claims,
+        Err(error) => return Err(Error::Serialize(error)),
+    };
+    let message = format!("{}.{}",
+                          JWT_HEADER.as_bytes().to_base64(URL_SAFE),
+                          claims.as_bytes().to_base64(URL_SAFE));
+    let signature = key.sign_with_hash(message.as_bytes(), Type::SHA256);
+    Ok(format!("{}.{}", message, signature.to_base64(URL_SAFE)))
+}
+
+/// Returns the current time, in UTC epoch seconds
+pub fn now() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_) => 0,
+    }
+}
+
+/// Returns the UTC epoch seconds that corresponds to a timestamp like `2016-07-11T22:14:10Z`
+pub fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let timestamp = timestamp.trim_right_matches('Z');
+    let mut parts = timestamp.splitn(2, 'T');
+    let date: Vec<u64> = match parts.next() {
+        Some(date) => date.split('-').filter_map(|part| part.parse().ok()).collect(),
+        None => return None,
+    };
+    let time: Vec<u64> = match parts.next() {
+        Some(time) => time.split(':').filter_map(|part| part.parse().ok()).collect(),
+        None => return None,
+    };
+    if date.len() != 3 || time.len() != 3 || date[1] < 1 || date[1] > 12 {
+        return None;
+    }
+    // Days since the epoch of a date in the proleptic Gregorian calendar
+    let (year, month, day) = if date[1] <= 2 {
+        (date[0] - 1, date[1] + 9, date[2])
+    } else {
+        (date[0], date[1] - 3, date[2])
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146097 + day_of_era).checked_sub(719468);
+    days.map(|days| days * 86400 + time[0] * 3600 + time[1] * 60 + time[2])
+}
+
+/// An installation of a Github App, on a user or organization account
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Installation {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub account: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub app_id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_type: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repository_selection: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub access_tokens_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repositories_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// An access token for an installation of a Github App
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallationToken {
+    pub token: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repository_selection: Option<String>,
+}
+
+impl InstallationToken {
+    /// Returns the time (in UTC epoch seconds) at which the token must be refreshed
+    pub fn refresh_at(&self) -> u64 {
+        let expires_at = match self.expires_at {
+            Some(ref expires_at) => parse_timestamp(expires_at),
+            None => None,
+        };
+        let expires_at = expires_at.unwrap_or_else(|| now() + DEFAULT_TOKEN_LIFETIME);
+        expires_at.saturating_sub(TOKEN_REFRESH_MARGIN)
+    }
+}
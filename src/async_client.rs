@@ -0,0 +1,273 @@
+///
+/// Module to compose with futures (available with the `async` feature).
+/// The transport of the Github client (Hyper 0.9) is blocking: requests are sent from a dedicated
+/// pool of threads, so that the caller (like an event loop) is never blocked, and the number of
+/// concurrent requests is bounded by the size of the pool.
+/// The protocol (authentication, headers, errors, rate limits, retries and cache) is the one of the
+/// blocking Github client, which holds the configuration.
+///
+use client::{GithubClient, RequestOptions};
+use error::Error;
+use futures_cpupool::{CpuFuture, CpuPool};
+use GetterAPI;
+use hyper::header::Headers;
+use hyper::method::Method;
+use pagination::{ListOptions, Page};
+use rate_limits::{Limits, RateLimits};
+use repo::{RepoClient, RepoInfoStructure};
+use serde::Deserialize;
+use UpdaterAPI;
+use user::{UserClient, UserInfoStructure, UserUpdateStructure};
+
+use std::sync::Arc;
+
+/// A future that resolves to the result of a request sent to the Github API
+pub type GithubFuture<T> = CpuFuture<T, Error>;
+
+/// A trait for structures that send a GET request, without blocking
+pub trait AsyncGetterAPI {
+    type GetType: ::std::fmt::Debug + ::serde::Serialize + ::serde::Deserialize;
+    fn get(&self) -> GithubFuture<Self::GetType>;
+}
+
+/// A trait for structures that send a PATCH request, without blocking
+pub trait AsyncUpdaterAPI {
+    type PatchType: ::std::fmt::Debug + ::serde::Serialize + ::serde::Deserialize;
+    fn patch(&self, &Self::PatchType) -> GithubFuture<String>;
+}
+
+/// A client to communicate with the Github API without blocking.
+/// The client can be cloned cheaply, and all the clones share the same Github client and pool.
+#[derive(Clone)]
+pub struct AsyncGithubClient {
+    /// The Github client, which holds the configuration and the state (rate limit, access token of
+    /// an installation, cache)
+    github_client: Arc<GithubClient>,
+    /// The pool of threads that send the requests
+    pool: CpuPool,
+}
+
+impl AsyncGithubClient {
+    /// Returns a client to communicate with the Github API without blocking
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client, which holds the configuration (authentication,
+    /// URLs, policies, cache, ...)
+    /// * `threads` - The number of requests that can be sent at the same time
+    ///
+    /// # Example
+    ///
+    /// `let async_client = AsyncGithubClient::new(GithubClient::new("k0pernicus", "myapikey0123456789"), 16);`
+    pub fn new(github_client: GithubClient, threads: usize) -> Self {
+        AsyncGithubClient {
+            github_client: Arc::new(github_client),
+            pool: CpuPool::new(threads),
+        }
+    }
+
+    /// Returns the (blocking) Github client
+    pub fn blocking(&self) -> &GithubClient {
+        &self.github_client
+    }
+
+    /// Runs a function that uses the (blocking) Github client in the pool, and returns a future
+    /// that resolves to its result.
+    /// This gives access to every API of the crate without blocking.
+    ///
+    /// # Argument
+    ///
+    /// * `function` - The function to run
+    ///
+    /// # Example
+    ///
+    /// `let repo = async_client.run(|client| RepoClient::new(client, "k0pernicus", "github-api-rs").get());`
+    pub fn run<F, T>(&self, function: F) -> GithubFuture<T>
+        where F: FnOnce(&GithubClient) -> Result<T, Error> + Send + 'static,
+              T: Send + 'static
+    {
+        let github_client = self.github_client.clone();
+        self.pool.spawn_fn(move || function(&github_client))
+    }
+
+    /// Process a request with some options without blocking, like `GithubClient::request`, and
+    /// returns a future that resolves to the body of the response with its headers
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `options` - The options of the request
+    pub fn request(&self,
+                   http_method: Method,
+                   url: &str,
+                   body: Option<String>,
+                   options: &RequestOptions)
+                   -> GithubFuture<(String, Headers)> {
+        let url = url.to_owned();
+        let options = options.clone();
+        self.run(move |github_client| github_client.request(http_method, &url, body, &options))
+    }
+
+    /// Process a request without blocking, like `GithubClient::process_request`
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    pub fn process_request(&self,
+                           http_method: Method,
+                           url: &str,
+                           body: Option<String>)
+                           -> GithubFuture<String> {
+        let url = url.to_owned();
+        self.run(move |github_client| github_client.process_request(http_method, &url, body))
+    }
+
+    /// Process a request without blocking, like `GithubClient::request_json`, and returns a future
+    /// that resolves to the body of the response converted to a given structure
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    ///
+    /// # Example
+    ///
+    /// `let issue = async_client.request_json::<Issue>(Method::Get, "repos/k0pernicus/github-api-rs/issues/1", None);`
+    pub fn request_json<T>(&self,
+                           http_method: Method,
+                           url: &str,
+                           body: Option<String>)
+                           -> GithubFuture<T>
+        where T: Deserialize + Send + 'static
+    {
+        let url = url.to_owned();
+        self.run(move |github_client| github_client.request_json(http_method, &url, body))
+    }
+
+    /// Returns a future that resolves to a page of a list of items, like `GithubClient::get_page`
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice that represent the URL of the list
+    /// * `options` - The pagination parameters (page number and items per page)
+    pub fn get_page<T>(&self, url: &str, options: &ListOptions) -> GithubFuture<Page<T>>
+        where T: Deserialize + Send + 'static
+    {
+        let url = url.to_owned();
+        let options = options.clone();
+        self.run(move |github_client| github_client.get_page(&url, &options))
+    }
+
+    /// Returns a User client to communicate with the Github API about a User, without blocking
+    ///
+    /// # Argument
+    ///
+    /// * `username` - A string slice that holds the username of a Github user
+    pub fn get_user_client(&self, username: &str) -> AsyncUserClient {
+        AsyncUserClient {
+            client: self.clone(),
+            username: Some(username.to_owned()),
+        }
+    }
+
+    /// Returns a User client that corresponding to the current user, without blocking.
+    /// If the login of the current user is unknown, it is asked to Github with the first request.
+    pub fn get_myself_client(&self) -> AsyncUserClient {
+        AsyncUserClient {
+            client: self.clone(),
+            username: None,
+        }
+    }
+
+    /// Returns a Repository client to communicate with the Github API, without blocking
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The owner of the repository to get informations about
+    /// * `reponame` - The repository name to get informations about
+    pub fn get_repo_client(&self, owner: &str, reponame: &str) -> AsyncRepoClient {
+        AsyncRepoClient {
+            client: self.clone(),
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns a client to get informations about rate limits, without blocking
+    pub fn get_rate_limits(&self) -> AsyncRateLimits {
+        AsyncRateLimits { client: self.clone() }
+    }
+}
+
+/// A client to communicate with the Github API for Users, without blocking
+pub struct AsyncUserClient {
+    /// The asynchronous Github client
+    client: AsyncGithubClient,
+    /// The username of the user, or None for the authenticated user
+    username: Option<String>,
+}
+
+impl AsyncGetterAPI for AsyncUserClient {
+    type GetType = UserInfoStructure;
+    /// Returns a future that resolves to a UserInfoStructure structure, or an Error
+    fn get(&self) -> GithubFuture<UserInfoStructure> {
+        let username = self.username.clone();
+        self.client.run(move |github_client| {
+            match username {
+                Some(ref username) => UserClient::new(github_client, username).get(),
+                None => github_client.get_myself_client().and_then(|myself| myself.get()),
+            }
+        })
+    }
+}
+
+impl AsyncUpdaterAPI for AsyncUserClient {
+    type PatchType = UserUpdateStructure;
+    /// Update the current informations about the authenticated user, and returns a future that
+    /// resolves to a message from the server
+    fn patch(&self, new_infos: &UserUpdateStructure) -> GithubFuture<String> {
+        let new_infos = new_infos.clone();
+        self.client.run(move |github_client| {
+            github_client.get_myself_client().and_then(|myself| myself.patch(&new_infos))
+        })
+    }
+}
+
+/// A client to communicate with the Github API about a repository, without blocking
+pub struct AsyncRepoClient {
+    /// The asynchronous Github client
+    client: AsyncGithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl AsyncGetterAPI for AsyncRepoClient {
+    type GetType = RepoInfoStructure;
+    /// Returns a future that resolves to a RepoInfoStructure structure, or an Error
+    fn get(&self) -> GithubFuture<RepoInfoStructure> {
+        let owner = self.owner.clone();
+        let reponame = self.reponame.clone();
+        self.client.run(move |github_client| RepoClient::new(github_client, &owner, &reponame).get())
+    }
+}
+
+/// A client to get informations about rate limits, without blocking
+pub struct AsyncRateLimits {
+    /// The asynchronous Github client
+    client: AsyncGithubClient,
+}
+
+impl AsyncGetterAPI for AsyncRateLimits {
+    type GetType = Limits;
+    /// Returns a future that resolves to a Limits structure, or an Error
+    fn get(&self) -> GithubFuture<Limits> {
+        self.client.run(|github_client| RateLimits::new(github_client).get())
+    }
+}
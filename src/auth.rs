@@ -0,0 +1,76 @@
+///
+/// Module to compose with authentication.
+/// Github documentation available at https://developer.github.com/v3/#authentication.
+///
+use std::fmt;
+
+/// The way to authenticate the requests sent to the Github API
+#[derive(Clone, PartialEq)]
+pub enum Auth {
+    /// No authentication: only public data can be read, with a lower rate limit
+    Anonymous,
+    /// A personal access token, sent as `Authorization: token <token>`
+    Token(String),
+    /// A token sent as `Authorization: Bearer <token>`
+    Bearer(String),
+    /// HTTP Basic authentication, using a username and a password (or a personal access token)
+    Basic {
+        /// The username of the user
+        username: String,
+        /// The password, or the personal API key, of the user
+        password: String,
+    },
+    /// OAuth application credentials, sent as `client_id` and `client_secret` query parameters
+    OAuth {
+        /// The client ID of the OAuth application
+        client_id: String,
+        /// The client secret of the OAuth application
+        client_secret: String,
+    },
+    /// Github App authentication, using RS256 signed JWTs.
+    /// If an installation is given, requests are authenticated with an access token of this
+    /// installation, refreshed before it expires.
+    App {
+        /// The identifier of the Github App
+        app_id: usize,
+        /// The PEM encoded private key of the Github App
+        private_key: Vec<u8>,
+        /// The identifier of the installation to act as, if any
+        installation_id: Option<usize>,
+    },
+}
+
+impl Auth {
+    /// Returns the query parameters to append to every URL, for OAuth application credentials
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            Auth::OAuth { ref client_id, ref client_secret } => {
+                vec![("client_id", client_id.to_owned()), ("client_secret", client_secret.to_owned())]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Secrets are not displayed, to avoid leaking them in logs
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Auth::Anonymous => write!(f, "Anonymous"),
+            Auth::Token(_) => write!(f, "Token(***)"),
+            Auth::Bearer(_) => write!(f, "Bearer(***)"),
+            Auth::Basic { ref username, .. } => {
+                write!(f, "Basic {{ username: {:?}, password: *** }}", username)
+            }
+            Auth::OAuth { ref client_id, .. } => {
+                write!(f, "OAuth {{ client_id: {:?}, client_secret: *** }}", client_id)
+            }
+            Auth::App { app_id, installation_id, .. } => {
+                write!(f,
+                       "App {{ app_id: {:?}, private_key: ***, installation_id: {:?} }}",
+                       app_id,
+                       installation_id)
+            }
+        }
+    }
+}
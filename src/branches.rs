@@ -0,0 +1,376 @@
+///
+/// Module to compose with branches, and their protection.
+/// Github documentation available at https://developer.github.com/v3/repos/branches/.
+///
+use client::{GithubClient, append_query, encode, encode_segment};
+use commits::Commit;
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use reviews::Team;
+use user::UserInfoStructure;
+
+/// Structure that represents a branches client.
+/// This structure is needed to communicate with the Github API, about the branches of a
+/// repository and their protection.
+pub struct BranchesClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> BranchesClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the branches of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let branches_client = BranchesClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        BranchesClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the branches of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/branches", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the URL of the protection of a branch
+    fn protection_url(&self, branch: &str) -> String {
+        format!("{}/{}/protection", self.url(), encode_segment(branch))
+    }
+
+    /// Returns the branches of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `protected` - Only the protected (or the unprotected) branches, if given
+    /// * `options` - The pagination parameters
+    pub fn list(&self, protected: Option<bool>, options: &ListOptions) -> Pages<'a, Branch> {
+        let url = match protected {
+            Some(protected) => append_query(&self.url(), &[("protected", protected.to_string())]),
+            None => self.url(),
+        };
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a branch, with its last commit
+    ///
+    /// # Argument
+    ///
+    /// * `branch` - The name of the branch
+    pub fn get(&self, branch: &str) -> Result<Branch, Error> {
+        let url = format!("{}/{}", self.url(), encode_segment(branch));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Renames a branch, and returns it.
+    /// The pull requests and the protection rules of the branch follow the new name.
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The current name of the branch
+    /// * `new_name` - The new name of the branch
+    pub fn rename(&self, branch: &str, new_name: &str) -> Result<Branch, Error> {
+        let url = format!("{}/{}/rename", self.url(), encode_segment(branch));
+        let body = try!(encode(&BranchRenameStructure { new_name: new_name.to_owned() }));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns the protection of a branch (Github answers "404 Not Found" if the branch is not
+    /// protected)
+    ///
+    /// # Argument
+    ///
+    /// * `branch` - The name of the branch
+    pub fn get_protection(&self, branch: &str) -> Result<BranchProtection, Error> {
+        self.github_client.request_json(Method::Get, &self.protection_url(branch), None)
+    }
+
+    /// Protects a branch, replacing all its protection rules, and returns the protection
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the branch
+    /// * `protection` - The protection rules
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let protection = BranchProtectionUpdate {
+    ///     required_status_checks: Some(RequiredStatusChecks { strict: true, contexts: vec![String::from("ci/build")] }),
+    ///     enforce_admins: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let protection = branches_client.update_protection("master", &protection);
+    /// `
+    pub fn update_protection(&self,
+                             branch: &str,
+                             protection: &BranchProtectionUpdate)
+                             -> Result<BranchProtection, Error> {
+        let body = try!(encode(protection));
+        self.github_client.request_json(Method::Put, &self.protection_url(branch), Some(body))
+    }
+
+    /// Removes the protection of a branch
+    ///
+    /// # Argument
+    ///
+    /// * `branch` - The name of the branch
+    pub fn delete_protection(&self, branch: &str) -> Result<(), Error> {
+        self.github_client
+            .process_request(Method::Delete, &self.protection_url(branch), None)
+            .map(|_| ())
+    }
+
+    /// Updates the required status checks of a protected branch, and returns them
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the branch
+    /// * `checks` - The required status checks
+    pub fn update_required_status_checks(&self,
+                                         branch: &str,
+                                         checks: &RequiredStatusChecks)
+                                         -> Result<StatusChecksProtection, Error> {
+        let url = format!("{}/required_status_checks", self.protection_url(branch));
+        let body = try!(encode(checks));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Updates the required reviews of a protected branch, and returns them
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the branch
+    /// * `reviews` - The required reviews
+    pub fn update_required_reviews(&self,
+                                   branch: &str,
+                                   reviews: &RequiredReviews)
+                                   -> Result<ReviewsProtection, Error> {
+        let url = format!("{}/required_pull_request_reviews", self.protection_url(branch));
+        let body = try!(encode(reviews));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Enables (or disables) the enforcement of the protection for the administrators of a
+    /// protected branch
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the branch
+    /// * `enabled` - Enforces the protection for the administrators
+    pub fn set_enforce_admins(&self, branch: &str, enabled: bool) -> Result<(), Error> {
+        let url = format!("{}/enforce_admins", self.protection_url(branch));
+        self.set_flag(&url, enabled)
+    }
+
+    /// Returns true if the commits pushed to a protected branch must be signed
+    ///
+    /// # Argument
+    ///
+    /// * `branch` - The name of the branch
+    pub fn required_signatures(&self, branch: &str) -> Result<bool, Error> {
+        let url = format!("{}/required_signatures", self.protection_url(branch));
+        let flag: ProtectionFlag = try!(self.github_client.request_json(Method::Get, &url, None));
+        Ok(flag.enabled.unwrap_or(false))
+    }
+
+    /// Requires (or not) the commits pushed to a protected branch to be signed
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the branch
+    /// * `enabled` - Requires signed commits
+    pub fn set_required_signatures(&self, branch: &str, enabled: bool) -> Result<(), Error> {
+        let url = format!("{}/required_signatures", self.protection_url(branch));
+        self.set_flag(&url, enabled)
+    }
+
+    /// Enables a protection setting with a POST request, or disables it with a DELETE request
+    fn set_flag(&self, url: &str, enabled: bool) -> Result<(), Error> {
+        let method = if enabled { Method::Post } else { Method::Delete };
+        self.github_client.process_request(method, url, None).map(|_| ())
+    }
+}
+
+/// Contains all necessary fields to define a branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Branch {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    /// The last commit of the branch (only the SHA and the URL, in a list)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit: Option<Commit>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub protected: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub protection_url: Option<String>,
+}
+
+/// Structure sent to rename a branch
+#[derive(Serialize)]
+struct BranchRenameStructure {
+    new_name: String,
+}
+
+/// The status checks that must pass before merging into a protected branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequiredStatusChecks {
+    /// Requires the branches to be up to date with the protected branch before merging
+    pub strict: bool,
+    /// The contexts of the status checks, like `ci/build`
+    pub contexts: Vec<String>,
+}
+
+/// The users and teams allowed to dismiss reviews
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DismissalRestrictions {
+    /// The logins of the users
+    pub users: Vec<String>,
+    /// The slugs of the teams
+    pub teams: Vec<String>,
+}
+
+/// The reviews required before merging into a protected branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequiredReviews {
+    /// Restricts the users and teams allowed to dismiss reviews (anyone with write access if
+    /// `None`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dismissal_restrictions: Option<DismissalRestrictions>,
+    /// Dismisses the approving reviews when new commits are pushed
+    pub dismiss_stale_reviews: bool,
+    /// Requires a review from the code owners
+    pub require_code_owner_reviews: bool,
+    /// The number of approving reviews required (between 1 and 6)
+    pub required_approving_review_count: usize,
+}
+
+/// The users, teams and apps allowed to push to a protected branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Restrictions {
+    /// The logins of the users
+    pub users: Vec<String>,
+    /// The slugs of the teams
+    pub teams: Vec<String>,
+    /// The slugs of the Github Apps
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub apps: Vec<String>,
+}
+
+/// The protection rules of a branch, to replace the current ones.
+/// The four first rules are always sent: `None` disables the rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BranchProtectionUpdate {
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    /// Enforces the protection rules for the administrators
+    pub enforce_admins: Option<bool>,
+    pub required_pull_request_reviews: Option<RequiredReviews>,
+    /// Restricts who can push (only for the repositories of an organization)
+    pub restrictions: Option<Restrictions>,
+    /// Forbids the merge commits
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_linear_history: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub allow_force_pushes: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub allow_deletions: Option<bool>,
+    /// Requires all the conversations on the code to be resolved before merging
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_conversation_resolution: Option<bool>,
+}
+
+/// A protection setting that can be enabled or disabled
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProtectionFlag {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// The required status checks of a protected branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusChecksProtection {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub strict: Option<bool>,
+    #[serde(default)]
+    pub contexts: Vec<String>,
+}
+
+/// The users and teams allowed to dismiss the reviews of a protected branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DismissalRestrictionsProtection {
+    #[serde(default)]
+    pub users: Vec<UserInfoStructure>,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+}
+
+/// The required reviews of a protected branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReviewsProtection {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dismissal_restrictions: Option<DismissalRestrictionsProtection>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dismiss_stale_reviews: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub require_code_owner_reviews: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_approving_review_count: Option<usize>,
+}
+
+/// The users and teams allowed to push to a protected branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RestrictionsProtection {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub users: Vec<UserInfoStructure>,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+}
+
+/// Contains all necessary fields to define the protection of a branch
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BranchProtection {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_status_checks: Option<StatusChecksProtection>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_pull_request_reviews: Option<ReviewsProtection>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub enforce_admins: Option<ProtectionFlag>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub restrictions: Option<RestrictionsProtection>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_linear_history: Option<ProtectionFlag>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub allow_force_pushes: Option<ProtectionFlag>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub allow_deletions: Option<ProtectionFlag>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_conversation_resolution: Option<ProtectionFlag>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub required_signatures: Option<ProtectionFlag>,
+}
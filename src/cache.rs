@@ -0,0 +1,188 @@
+///
+/// Module to compose with conditional requests.
+/// Github documentation available at https://developer.github.com/v3/#conditional-requests.
+///
+use hyper::header::Headers;
+use openssl::crypto::hash::{Type, hash};
+use rustc_serialize::hex::ToHex;
+use serde_json;
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Name of the header that contains the entity tag of a response
+pub const ETAG_HEADER: &'static str = "ETag";
+/// Name of the header that contains the last modification date of a response
+pub const LAST_MODIFIED_HEADER: &'static str = "Last-Modified";
+/// Name of the header that contains the links to other pages of a response
+const LINK_HEADER: &'static str = "Link";
+
+/// A response saved in a cache, to be served again if Github answers "304 Not Modified"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// The ETag header of the response
+    pub etag: Option<String>,
+    /// The Last-Modified header of the response
+    pub last_modified: Option<String>,
+    /// The Link header of the response, for paginated lists
+    pub link: Option<String>,
+    /// The body of the response
+    pub body: String,
+}
+
+impl CachedResponse {
+    /// Returns a response to save from the body and the headers of a response, or None if Github
+    /// did not give any validator (ETag or Last-Modified) for this response
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The body of the response
+    /// * `headers` - The headers of the response
+    pub fn from_response(body: &str, headers: &Headers) -> Option<CachedResponse> {
+        let etag = raw_header(headers, ETAG_HEADER);
+        let last_modified = raw_header(headers, LAST_MODIFIED_HEADER);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+        Some(CachedResponse {
+            etag: etag,
+            last_modified: last_modified,
+            link: raw_header(headers, LINK_HEADER),
+            body: body.to_owned(),
+        })
+    }
+
+    /// Returns the headers of a "304 Not Modified" response, completed with the saved Link header
+    ///
+    /// # Argument
+    ///
+    /// * `headers` - The headers of the "304 Not Modified" response
+    pub fn merge_headers(&self, headers: &Headers) -> Headers {
+        let mut headers = headers.clone();
+        if let Some(ref link) = self.link {
+            headers.set_raw(LINK_HEADER, vec![link.clone().into_bytes()]);
+        }
+        headers
+    }
+}
+
+/// Returns the value of a header as a string, if any
+fn raw_header(headers: &Headers, name: &str) -> Option<String> {
+    headers.get_raw(name)
+        .and_then(|values| values.first())
+        .map(|value| String::from_utf8_lossy(value).into_owned())
+}
+
+/// A cache of responses, indexed by URL.
+/// Only responses to GET requests are saved.
+pub trait ResponseCache: Send + Sync {
+    /// Returns the response saved for a given URL, if any
+    fn get(&self, url: &str) -> Option<CachedResponse>;
+    /// Saves the response for a given URL
+    fn set(&self, url: &str, response: CachedResponse);
+}
+
+/// A cache of responses, in memory
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    responses: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryCache {
+    /// Returns an empty cache
+    pub fn new() -> Self {
+        MemoryCache::default()
+    }
+}
+
+impl ResponseCache for MemoryCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        match self.responses.lock() {
+            Ok(responses) => responses.get(url).cloned(),
+            Err(poisoned) => poisoned.into_inner().get(url).cloned(),
+        }
+    }
+
+    fn set(&self, url: &str, response: CachedResponse) {
+        let mut responses = match self.responses.lock() {
+            Ok(responses) => responses,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        responses.insert(url.to_owned(), response);
+    }
+}
+
+/// An entry of the cache on disk
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    /// The URL of the response, to detect hash collisions
+    url: String,
+    /// The saved response
+    response: CachedResponse,
+}
+
+/// A cache of responses, on disk: each response is saved as a JSON file in a directory.
+/// Errors when reading or writing files are ignored, and considered as cache misses.
+#[derive(Debug)]
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+impl DiskCache {
+    /// Returns a cache that saves responses in a given directory, created if needed
+    ///
+    /// # Argument
+    ///
+    /// * `directory` - The path of the directory
+    pub fn new<P: AsRef<Path>>(directory: P) -> io::Result<Self> {
+        try!(fs::create_dir_all(directory.as_ref()));
+        Ok(DiskCache { directory: directory.as_ref().to_path_buf() })
+    }
+
+    /// Returns the path of the file that contains the response for a given URL
+    /// The file is named after the SHA-256 digest of the URL, which (unlike the hashers of the
+    /// standard library) does not change between Rust releases
+    fn path(&self, url: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", hash(Type::SHA256, url.as_bytes()).to_hex()))
+    }
+}
+
+impl ResponseCache for DiskCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        let mut content = String::new();
+        match File::open(self.path(url)) {
+            Ok(mut file) => {
+                if file.read_to_string(&mut content).is_err() {
+                    return None;
+                }
+            }
+            Err(_) => return None,
+        }
+        match serde_json::from_str::<DiskEntry>(&content) {
+            Ok(ref entry) if entry.url == url => Some(entry.response.clone()),
+            _ => None,
+        }
+    }
+
+    fn set(&self, url: &str, response: CachedResponse) {
+        let entry = DiskEntry {
+            url: url.to_owned(),
+            response: response,
+        };
+        let content = match serde_json::to_string(&entry) {
+            Ok(content) => content,
+            Err(_) => return,
+        };
+        // Write a temporary file first, to never leave a truncated entry
+        let path = self.path(url);
+        let temporary_path = path.with_extension("tmp");
+        let written = File::create(&temporary_path)
+            .and_then(|mut file| file.write_all(content.as_bytes()));
+        if written.is_ok() {
+            let _ = fs::rename(&temporary_path, &path);
+        }
+    }
+}
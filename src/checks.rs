@@ -0,0 +1,703 @@
+///
+/// Module to compose with checks.
+/// Github documentation available at https://developer.github.com/v3/checks/.
+///
+use client::{GithubClient, append_query, encode, encode_segment};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Maximum number of annotations that Github accepts in a single request
+const MAX_ANNOTATIONS: usize = 50;
+
+/// Structure that represents a checks client.
+/// This structure is needed to communicate with the Github API, about the check runs and the
+/// check suites of a repository.
+/// Check runs can only be created or updated by a Github App.
+pub struct ChecksClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> ChecksClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the checks of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let checks_client = ChecksClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        ChecksClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Creates a check run, and returns it.
+    /// If the output has more than 50 annotations, the remaining annotations are sent by updating
+    /// the check run, 50 at a time.
+    ///
+    /// # Argument
+    ///
+    /// * `check_run` - The check run to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let check_run = NewCheckRun::new("lint", "6dcb09b5b57875f334f61aebed695e2e4193db5e");
+    /// let check_run = checks_client.create_run(&check_run);
+    /// `
+    pub fn create_run(&self, check_run: &NewCheckRun) -> Result<CheckRun, Error> {
+        let url = format!("{}/check-runs", self.url());
+        let (output, batches) = split_output(&check_run.output);
+        let mut first = check_run.clone();
+        first.output = output;
+        let body = try!(encode(&first));
+        let created: CheckRun =
+            try!(self.github_client.request_json(Method::Post, &url, Some(body)));
+        match created.id {
+            Some(id) => self.send_annotations(id, batches, created),
+            None => Ok(created),
+        }
+    }
+
+    /// Updates a check run, and returns it.
+    /// If the output has more than 50 annotations, they are sent in several requests, 50 at a
+    /// time.
+    ///
+    /// # Arguments
+    ///
+    /// * `check_run_id` - The identifier of the check run
+    /// * `update` - The fields of the check run to update
+    pub fn update_run(&self,
+                      check_run_id: usize,
+                      update: &CheckRunUpdate)
+                      -> Result<CheckRun, Error> {
+        let url = format!("{}/check-runs/{}", self.url(), check_run_id);
+        let (output, batches) = split_output(&update.output);
+        let mut first = update.clone();
+        first.output = output;
+        let body = try!(encode(&first));
+        let updated = try!(self.github_client.request_json(Method::Patch, &url, Some(body)));
+        self.send_annotations(check_run_id, batches, updated)
+    }
+
+    /// Sends the remaining batches of annotations of a check run, and returns the last version of
+    /// the check run
+    fn send_annotations(&self,
+                        check_run_id: usize,
+                        batches: Vec<CheckRunOutput>,
+                        check_run: CheckRun)
+                        -> Result<CheckRun, Error> {
+        let url = format!("{}/check-runs/{}", self.url(), check_run_id);
+        let mut check_run = check_run;
+        for output in batches {
+            let body = try!(encode(&CheckRunUpdate {
+                output: Some(output),
+                ..Default::default()
+            }));
+            check_run = try!(self.github_client.request_json(Method::Patch, &url, Some(body)));
+        }
+        Ok(check_run)
+    }
+
+    /// Returns a check run
+    ///
+    /// # Argument
+    ///
+    /// * `check_run_id` - The identifier of the check run
+    pub fn get_run(&self, check_run_id: usize) -> Result<CheckRun, Error> {
+        let url = format!("{}/check-runs/{}", self.url(), check_run_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the annotations of a check run
+    ///
+    /// # Arguments
+    ///
+    /// * `check_run_id` - The identifier of the check run
+    /// * `options` - The pagination parameters
+    pub fn list_annotations(&self,
+                            check_run_id: usize,
+                            options: &ListOptions)
+                            -> Pages<'a, CheckAnnotation> {
+        let url = format!("{}/check-runs/{}/annotations", self.url(), check_run_id);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the check runs of a commit
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list_runs_for_ref(&self,
+                             reference: &str,
+                             filters: &CheckRunListOptions,
+                             options: &ListOptions)
+                             -> Result<CheckRunList, Error> {
+        let url = format!("{}/commits/{}/check-runs", self.url(), encode_segment(reference));
+        self.list_runs(&url, filters, options)
+    }
+
+    /// Returns the check runs of a check suite
+    ///
+    /// # Arguments
+    ///
+    /// * `check_suite_id` - The identifier of the check suite
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list_runs_for_suite(&self,
+                               check_suite_id: usize,
+                               filters: &CheckRunListOptions,
+                               options: &ListOptions)
+                               -> Result<CheckRunList, Error> {
+        let url = format!("{}/check-suites/{}/check-runs", self.url(), check_suite_id);
+        self.list_runs(&url, filters, options)
+    }
+
+    /// Returns a page of check runs
+    fn list_runs(&self,
+                 url: &str,
+                 filters: &CheckRunListOptions,
+                 options: &ListOptions)
+                 -> Result<CheckRunList, Error> {
+        let mut params = filters.to_params();
+        params.extend(options.to_params());
+        self.github_client.request_json(Method::Get, &append_query(url, &params), None)
+    }
+
+    /// Asks Github to run a check run again (the Github App receives a `check_run` event with the
+    /// `rerequested` action)
+    ///
+    /// # Argument
+    ///
+    /// * `check_run_id` - The identifier of the check run
+    pub fn rerequest_run(&self, check_run_id: usize) -> Result<(), Error> {
+        let url = format!("{}/check-runs/{}/rerequest", self.url(), check_run_id);
+        self.github_client.process_request(Method::Post, &url, None).map(|_| ())
+    }
+
+    /// Creates a check suite for a commit, and returns it (only needed when the automatic
+    /// creation of check suites has been disabled)
+    ///
+    /// # Argument
+    ///
+    /// * `head_sha` - The SHA of the commit
+    pub fn create_suite(&self, head_sha: &str) -> Result<CheckSuite, Error> {
+        let url = format!("{}/check-suites", self.url());
+        let body = try!(encode(&NewCheckSuite { head_sha: head_sha.to_owned() }));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns a check suite
+    ///
+    /// # Argument
+    ///
+    /// * `check_suite_id` - The identifier of the check suite
+    pub fn get_suite(&self, check_suite_id: usize) -> Result<CheckSuite, Error> {
+        let url = format!("{}/check-suites/{}", self.url(), check_suite_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the check suites of a commit
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    /// * `options` - The pagination parameters
+    pub fn list_suites_for_ref(&self,
+                               reference: &str,
+                               options: &ListOptions)
+                               -> Result<CheckSuiteList, Error> {
+        let url = format!("{}/commits/{}/check-suites", self.url(), encode_segment(reference));
+        let url = append_query(&url, &options.to_params());
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Asks Github to run a check suite again (the Github App receives a `check_suite` event with
+    /// the `rerequested` action)
+    ///
+    /// # Argument
+    ///
+    /// * `check_suite_id` - The identifier of the check suite
+    pub fn rerequest_suite(&self, check_suite_id: usize) -> Result<(), Error> {
+        let url = format!("{}/check-suites/{}/rerequest", self.url(), check_suite_id);
+        self.github_client.process_request(Method::Post, &url, None).map(|_| ())
+    }
+}
+
+/// Returns the output to send with the first request, and the outputs to send afterwards with
+/// the remaining annotations, if there are more annotations than Github accepts at once.
+/// Github replaces the title, the summary and the text with each update, and appends the
+/// annotations and the images: the images are only sent with the first output.
+///
+/// # Argument
+///
+/// * `output` - The output of a check run
+pub fn split_output(output: &Option<CheckRunOutput>)
+                    -> (Option<CheckRunOutput>, Vec<CheckRunOutput>) {
+    match *output {
+        Some(ref output) if output.annotations.len() > MAX_ANNOTATIONS => {
+            let mut chunks = output.annotations.chunks(MAX_ANNOTATIONS);
+            let mut first = output.clone();
+            first.annotations = chunks.next().map_or(Vec::new(), |chunk| chunk.to_vec());
+            let batches = chunks.map(|chunk| {
+                    CheckRunOutput {
+                        title: output.title.clone(),
+                        summary: output.summary.clone(),
+                        text: output.text.clone(),
+                        annotations: chunk.to_vec(),
+                        images: Vec::new(),
+                    }
+                })
+                .collect();
+            (Some(first), batches)
+        }
+        _ => (output.clone(), Vec::new()),
+    }
+}
+
+/// The status of a check run, or of a check suite
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckStatus {
+    Queued,
+    InProgress,
+    Completed,
+    /// Only set by Github Actions
+    Requested,
+    /// Only set by Github Actions, when a job waits for a deployment protection rule
+    Waiting,
+    /// Only set by Github Actions, when a job waits for a concurrency group
+    Pending,
+    /// A status that this crate does not know yet
+    Unknown(String),
+}
+
+impl CheckStatus {
+    /// Returns the value of the `status` query parameter
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CheckStatus::Queued => "queued",
+            CheckStatus::InProgress => "in_progress",
+            CheckStatus::Completed => "completed",
+            CheckStatus::Requested => "requested",
+            CheckStatus::Waiting => "waiting",
+            CheckStatus::Pending => "pending",
+            CheckStatus::Unknown(ref status) => status,
+        }
+    }
+}
+
+impl Serialize for CheckStatus {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Deserialize for CheckStatus {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        let status = try!(String::deserialize(deserializer));
+        Ok(match status.as_str() {
+            "queued" => CheckStatus::Queued,
+            "in_progress" => CheckStatus::InProgress,
+            "completed" => CheckStatus::Completed,
+            "requested" => CheckStatus::Requested,
+            "waiting" => CheckStatus::Waiting,
+            "pending" => CheckStatus::Pending,
+            _ => CheckStatus::Unknown(status.clone()),
+        })
+    }
+}
+
+/// The conclusion of a completed check run, or check suite
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckConclusion {
+    Success,
+    Failure,
+    Neutral,
+    Cancelled,
+    Skipped,
+    TimedOut,
+    /// Additional actions are required (the `details_url` must be given)
+    ActionRequired,
+    /// Set by Github, when a check run has been incomplete for too long
+    Stale,
+    /// A conclusion that this crate does not know yet
+    Unknown(String),
+}
+
+impl CheckConclusion {
+    /// Returns the value of the conclusion, as sent to Github
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CheckConclusion::Success => "success",
+            CheckConclusion::Failure => "failure",
+            CheckConclusion::Neutral => "neutral",
+            CheckConclusion::Cancelled => "cancelled",
+            CheckConclusion::Skipped => "skipped",
+            CheckConclusion::TimedOut => "timed_out",
+            CheckConclusion::ActionRequired => "action_required",
+            CheckConclusion::Stale => "stale",
+            CheckConclusion::Unknown(ref conclusion) => conclusion,
+        }
+    }
+}
+
+impl Serialize for CheckConclusion {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Deserialize for CheckConclusion {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        let conclusion = try!(String::deserialize(deserializer));
+        Ok(match conclusion.as_str() {
+            "success" => CheckConclusion::Success,
+            "failure" => CheckConclusion::Failure,
+            "neutral" => CheckConclusion::Neutral,
+            "cancelled" => CheckConclusion::Cancelled,
+            "skipped" => CheckConclusion::Skipped,
+            "timed_out" => CheckConclusion::TimedOut,
+            "action_required" => CheckConclusion::ActionRequired,
+            "stale" => CheckConclusion::Stale,
+            _ => CheckConclusion::Unknown(conclusion.clone()),
+        })
+    }
+}
+
+/// The level of an annotation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AnnotationLevel {
+    #[serde(rename = "notice")]
+    Notice,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "failure")]
+    Failure,
+}
+
+/// An annotation of a line (or of several lines) of a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckAnnotation {
+    /// The path of the file, in the repository
+    pub path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Only if the annotation is on a single line
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_column: Option<usize>,
+    /// Only if the annotation is on a single line
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub end_column: Option<usize>,
+    pub annotation_level: AnnotationLevel,
+    pub message: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub raw_details: Option<String>,
+    /// The URL of the annotated file (sent by Github)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub blob_href: Option<String>,
+}
+
+impl CheckAnnotation {
+    /// Returns an annotation of some lines of a file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `start_line` - The first line of the annotation
+    /// * `end_line` - The last line of the annotation
+    /// * `level` - The level of the annotation
+    /// * `message` - A short description of the annotation
+    pub fn new(path: &str,
+               start_line: usize,
+               end_line: usize,
+               level: AnnotationLevel,
+               message: &str)
+               -> Self {
+        CheckAnnotation {
+            path: path.to_owned(),
+            start_line: start_line,
+            end_line: end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level: level,
+            message: message.to_owned(),
+            title: None,
+            raw_details: None,
+            blob_href: None,
+        }
+    }
+}
+
+/// An image shown in the output of a check run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckImage {
+    pub alt: String,
+    pub image_url: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// A button shown with a check run, that sends a `check_run` event with the `requested_action`
+/// action to the Github App
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckAction {
+    /// The text of the button (at most 20 characters)
+    pub label: String,
+    /// The description of the action (at most 40 characters)
+    pub description: String,
+    /// The identifier of the action, sent back to the Github App (at most 20 characters)
+    pub identifier: String,
+}
+
+/// The output of a check run, to send
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckRunOutput {
+    pub title: String,
+    /// The summary of the check run, in Markdown
+    pub summary: String,
+    /// The details of the check run, in Markdown
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub text: Option<String>,
+    /// The annotations (sent 50 at a time by the client)
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub annotations: Vec<CheckAnnotation>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub images: Vec<CheckImage>,
+}
+
+/// Fields to create a check run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewCheckRun {
+    pub name: String,
+    /// The SHA of the checked commit
+    pub head_sha: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub details_url: Option<String>,
+    /// The identifier of the check run in the system of the integrator
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub external_id: Option<String>,
+    /// The status of the check run (`Queued` by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub started_at: Option<String>,
+    /// The conclusion of the check run (it completes the check run)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub actions: Vec<CheckAction>,
+}
+
+impl NewCheckRun {
+    /// Returns the fields to create a check run
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the check, like `lint`
+    /// * `head_sha` - The SHA of the checked commit
+    pub fn new(name: &str, head_sha: &str) -> Self {
+        NewCheckRun {
+            name: name.to_owned(),
+            head_sha: head_sha.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Fields that can be modified for a given check run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckRunUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub actions: Vec<CheckAction>,
+}
+
+/// The check runs to list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckRunFilter {
+    /// The most recent check run of each name (the default)
+    Latest,
+    All,
+}
+
+/// Filters to list check runs
+#[derive(Debug, Clone, Default)]
+pub struct CheckRunListOptions {
+    /// Only the check runs with this name
+    pub check_name: Option<String>,
+    /// Only the check runs with this status
+    pub status: Option<CheckStatus>,
+    pub filter: Option<CheckRunFilter>,
+}
+
+impl CheckRunListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref check_name) = self.check_name {
+            params.push(("check_name", check_name.to_owned()));
+        }
+        if let Some(ref status) = self.status {
+            params.push(("status", status.as_str().to_owned()));
+        }
+        match self.filter {
+            Some(CheckRunFilter::Latest) => params.push(("filter", String::from("latest"))),
+            Some(CheckRunFilter::All) => params.push(("filter", String::from("all"))),
+            None => (),
+        }
+        params
+    }
+}
+
+/// The output of a check run, sent by Github
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckRunOutputSummary {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub annotations_count: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub annotations_url: Option<String>,
+}
+
+/// A reference to the check suite of a check run
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckSuiteRef {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+}
+
+/// Contains all necessary fields to define a check run
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckRun {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub head_sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub output: Option<CheckRunOutputSummary>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub check_suite: Option<CheckSuiteRef>,
+}
+
+/// A list of check runs, with the total number of check runs
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckRunList {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_count: Option<usize>,
+    #[serde(default)]
+    pub check_runs: Vec<CheckRun>,
+}
+
+/// Structure sent to create a check suite
+#[derive(Serialize)]
+struct NewCheckSuite {
+    head_sha: String,
+}
+
+/// Contains all necessary fields to define a check suite
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckSuite {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub head_branch: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub head_sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub latest_check_runs_count: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub check_runs_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// A list of check suites, with the total number of check suites
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckSuiteList {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_count: Option<usize>,
+    #[serde(default)]
+    pub check_suites: Vec<CheckSuite>,
+}
@@ -1,32 +1,393 @@
 use hyper::Client;
+use hyper::Url;
+use hyper::client::{Body, RedirectPolicy};
 use hyper::client::response::Response;
-use hyper::Error;
-use hyper::header::{Authorization, Basic, UserAgent};
+use hyper::header::{Authorization, Headers, Location, UserAgent};
 use hyper::method::Method;
-use hyper::status::StatusCode;
+use hyper::status::{StatusClass, StatusCode};
 
+use rustc_serialize::base64::{STANDARD, ToBase64};
+use serde::{Deserialize, Serialize};
 use serde_json;
+use url::form_urlencoded;
+use url::percent_encoding::{PATH_SEGMENT_ENCODE_SET, utf8_percent_encode};
 
-use std::io::Read;
+use std::io::{self, Read, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
-use user::UserClient;
+use app::{self, AppClient, InstallationToken};
+use auth::Auth;
+use cache::{CachedResponse, ResponseCache};
+use error::{Error, GitHubErrorResult};
+use pagination::{ListOptions, Page, Pages};
+use rate_limits::{Rate, RateLimitPolicy};
+use user::{USER_API_URL, UserClient, UserInfoStructure};
+use retry::RetryPolicy;
+use {RetryAfter, XRateLimitRemaining, XRateLimitReset};
 
 /// URL to access the Github API
 const GITHUB_API_URL: &'static str = "https://api.github.com";
+/// URL to upload files (like release assets) to the Github API
+const GITHUB_UPLOADS_URL: &'static str = "https://uploads.github.com";
 const USER_AGENT: &'static str = "[Github API] k0pernicus/github-api-rs";
+/// Name of the header to send the media types to accept
+const ACCEPT_HEADER: &'static str = "Accept";
+/// Name of the header to send the media type of an uploaded file
+const CONTENT_TYPE_HEADER: &'static str = "Content-Type";
+/// The media type accepted by default, for the version 3 of the Github API
+const DEFAULT_MEDIA_TYPE: &'static str = "application/vnd.github.v3+json";
+/// Name of the header to send the version of the REST API to use
+const API_VERSION_HEADER: &'static str = "X-GitHub-Api-Version";
+/// Name of the header to send the entity tag of a cached response
+const IF_NONE_MATCH_HEADER: &'static str = "If-None-Match";
+/// Name of the header to send the last modification date of a cached response
+const IF_MODIFIED_SINCE_HEADER: &'static str = "If-Modified-Since";
+/// Maximum number of redirections followed by a streamed request
+const MAX_REDIRECTS: usize = 5;
+
+/// A builder to create a Github client with a custom configuration, like the URL of a
+/// Github Enterprise Server instance
+pub struct GithubClientBuilder {
+    /// The login of the authenticated user, if known
+    username: Option<String>,
+    /// The way to authenticate requests
+    auth: Auth,
+    /// The base URL of the API
+    base_url: String,
+    /// The base URL used to upload files, if different from the default one
+    uploads_url: Option<String>,
+    /// What to do when the rate limit has been reached
+    rate_limit_policy: RateLimitPolicy,
+    /// How requests are sent again after a secondary rate limit or a server error
+    retry_policy: RetryPolicy,
+    /// The cache of responses, for conditional requests
+    cache: Option<Arc<ResponseCache>>,
+    /// The version of the REST API sent with each request, if any
+    api_version: Option<String>,
+}
+
+impl GithubClientBuilder {
+    /// Returns a builder that targets api.github.com, with anonymous requests by default
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let github_client = GithubClientBuilder::new()
+    ///     .auth(Auth::Token(String::from("myapikey0123456789")))
+    ///     .base_url("https://ghe.example.com/api/v3")
+    ///     .build();
+    /// `
+    pub fn new() -> Self {
+        GithubClientBuilder {
+            username: None,
+            auth: Auth::Anonymous,
+            base_url: GITHUB_API_URL.to_owned(),
+            uploads_url: None,
+            rate_limit_policy: RateLimitPolicy::default(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            api_version: None,
+        }
+    }
+
+    /// Sets the way to authenticate requests
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the login of the authenticated user.
+    /// If not set (and not given by Basic authentication), the login is asked to Github when needed.
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_owned());
+        self
+    }
+
+    /// Sets the base URL of the API, like `https://ghe.example.com/api/v3` for a
+    /// Github Enterprise Server instance, or the URL of a local server
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    /// Sets the base URL used to upload files.
+    /// If not set, the uploads URL is `https://uploads.github.com` for api.github.com,
+    /// `https://<host>/api/uploads` for a Github Enterprise Server instance, or the base URL
+    /// otherwise.
+    pub fn uploads_url(mut self, uploads_url: &str) -> Self {
+        self.uploads_url = Some(uploads_url.to_owned());
+        self
+    }
+
+    /// Sets what to do when the rate limit has been reached (returns an error by default)
+    pub fn rate_limit_policy(mut self, rate_limit_policy: RateLimitPolicy) -> Self {
+        self.rate_limit_policy = rate_limit_policy;
+        self
+    }
+
+    /// Sets how requests are sent again after a secondary rate limit, a server error or a
+    /// connection error (three attempts by default)
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets a cache of responses: GET requests become conditional requests, and the saved
+    /// response is served if Github answers "304 Not Modified" (which does not count against the
+    /// rate limit).
+    /// The cache can be shared between clients that use the same credentials.
+    pub fn cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets the version of the REST API to use (like `2022-11-28`), sent with each request in the
+    /// `X-GitHub-Api-Version` header.
+    /// The version can be overridden for a given request, using `RequestOptions`.
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.api_version = Some(api_version.to_owned());
+        self
+    }
+
+    /// Returns the Github client, or an error if one of the given URLs is not valid
+    pub fn build(self) -> Result<GithubClient, Error> {
+        let base_url = try!(validate_url(&self.base_url));
+        let uploads_url = match self.uploads_url {
+            Some(ref uploads_url) => try!(validate_url(uploads_url)),
+            None => default_uploads_url(&base_url),
+        };
+        let username = match (self.username, &self.auth) {
+            (Some(username), _) => Some(username),
+            (None, &Auth::Basic { ref username, .. }) => Some(username.to_owned()),
+            (None, _) => None,
+        };
+        Ok(GithubClient {
+            username: username,
+            auth: self.auth,
+            base_url: base_url,
+            uploads_url: uploads_url,
+            client: Client::new(),
+            stream_client: stream_client(),
+            installation_token: Mutex::new(None),
+            rate: Mutex::new(None),
+            rate_limit_policy: self.rate_limit_policy,
+            retry_policy: self.retry_policy,
+            cache: self.cache,
+            api_version: self.api_version,
+        })
+    }
+}
+
+/// Checks that the given URL is an absolute HTTP/HTTPS URL, and returns it without trailing slash
+fn validate_url(url: &str) -> Result<String, Error> {
+    match Url::parse(url) {
+        Ok(ref parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => {
+            Ok(url.trim_right_matches('/').to_owned())
+        }
+        _ => Err(Error::InvalidUrl(url.to_owned())),
+    }
+}
+
+/// Returns a Hyper client that does not follow redirections
+fn stream_client() -> Client {
+    let mut client = Client::new();
+    client.set_redirect_policy(RedirectPolicy::FollowNone);
+    client
+}
+
+/// Returns true if two URLs have the same host and port
+fn same_host(url: &str, other_url: &str) -> bool {
+    match (Url::parse(url), Url::parse(other_url)) {
+        (Ok(url), Ok(other_url)) => {
+            url.host_str() == other_url.host_str() &&
+            url.port_or_known_default() == other_url.port_or_known_default()
+        }
+        _ => false,
+    }
+}
+
+/// Copies a streamed response into a writer, and returns the number of bytes copied
+///
+/// # Arguments
+///
+/// * `response` - The response to read
+/// * `writer` - The destination of the content of the response
+pub fn copy_response<R: Read, W: Write>(response: &mut R, writer: &mut W) -> Result<u64, Error> {
+    let mut buffer = [0; 8192];
+    let mut copied = 0;
+    loop {
+        let read = match response.read(&mut buffer) {
+            Ok(0) => return Ok(copied),
+            Ok(read) => read,
+            Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(Error::from(error)),
+        };
+        try!(writer.write_all(&buffer[..read]).map_err(Error::Io));
+        copied += read as u64;
+    }
+}
+
+/// Returns the uploads URL that corresponds to a given (valid) base URL
+fn default_uploads_url(base_url: &str) -> String {
+    if base_url == GITHUB_API_URL {
+        GITHUB_UPLOADS_URL.to_owned()
+    } else if base_url.ends_with("/api/v3") {
+        format!("{}/api/uploads", &base_url[..base_url.len() - "/api/v3".len()])
+    } else {
+        base_url.to_owned()
+    }
+}
+
+/// Returns the URL with the given query parameters appended, URL-encoded
+///
+/// # Arguments
+///
+/// * `url` - A string slice that holds the URL, that can already contain query parameters
+/// * `params` - The query parameters to append, as (key, value) pairs
+///
+/// # Example
+///
+/// `let url = append_query("repos/k0pernicus/github-api-rs/issues", &[("state", String::from("open"))]);`
+pub fn append_query(url: &str, params: &[(&str, String)]) -> String {
+    if params.is_empty() {
+        return url.to_owned();
+    }
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for &(key, ref value) in params {
+        query.append_pair(key, value);
+    }
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}{}", url, separator, query.finish())
+}
+
+/// Returns a name (like a branch, a tag or a label) encoded to be a single segment of a URL path
+///
+/// # Argument
+///
+/// * `name` - The name, that can contain slashes and spaces
+///
+/// # Example
+///
+/// `let url = format!("repos/k0pernicus/github-api-rs/branches/{}", encode_segment("feature/a"));`
+pub fn encode_segment(name: &str) -> String {
+    utf8_percent_encode(name, PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
+/// Returns a structure converted to JSON, to be sent with a request
+///
+/// # Argument
+///
+/// * `value` - The structure to send
+pub fn encode<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(Error::Serialize)
+}
+
+/// Returns the body of a response converted to a given structure
+///
+/// # Argument
+///
+/// * `body` - The body of the response, in JSON
+pub fn decode<T: Deserialize>(body: String) -> Result<T, Error> {
+    match serde_json::from_str(&body) {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            Err(Error::Deserialize {
+                error: error,
+                body: body,
+            })
+        }
+    }
+}
+
+/// Options of a request sent to the Github API
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// The media type to accept, like `application/vnd.github.v3.html+json`
+    /// (`application/vnd.github.v3+json` by default)
+    pub accept: Option<String>,
+    /// The version of the REST API to use, if not the one of the client
+    pub api_version: Option<String>,
+    /// Extra headers to send, as (name, value) pairs
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    /// Returns the default options
+    pub fn new() -> Self {
+        RequestOptions::default()
+    }
+
+    /// Sets the media type to accept
+    ///
+    /// # Example
+    ///
+    /// `let options = RequestOptions::new().accept("application/vnd.github.v3.star+json");`
+    pub fn accept(mut self, media_type: &str) -> Self {
+        self.accept = Some(media_type.to_owned());
+        self
+    }
+
+    /// Opts into a preview of the API, by accepting its media type
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the preview, like `mercy` for
+    /// `application/vnd.github.mercy-preview+json`
+    pub fn preview(self, name: &str) -> Self {
+        self.accept(&format!("application/vnd.github.{}-preview+json", name))
+    }
+
+    /// Sets the version of the REST API to use, sent in the `X-GitHub-Api-Version` header
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.api_version = Some(api_version.to_owned());
+        self
+    }
+
+    /// Adds a header to send.
+    /// Extra headers replace the headers set by the client, if they have the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the header
+    /// * `value` - The value of the header
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+}
 
 /// A client to communicate with the Github API is represented here
 pub struct GithubClient {
-    /// The username of the user
-    pub username: String,
-    /// The personal API key of the user
-    pub api_key: String,
+    /// The login of the authenticated user, if known
+    pub username: Option<String>,
+    /// The way to authenticate requests
+    pub auth: Auth,
+    /// The base URL of the API (without trailing slash)
+    base_url: String,
+    /// The base URL used to upload files (without trailing slash)
+    uploads_url: String,
     /// The Hyper client
     client: Client,
+    /// The Hyper client for streamed responses, that does not follow redirections by itself
+    stream_client: Client,
+    /// The access token of the Github App installation, with the time at which it must be refreshed
+    installation_token: Mutex<Option<(InstallationToken, u64)>>,
+    /// The rate limit given by the last response
+    rate: Mutex<Option<Rate>>,
+    /// What to do when the rate limit has been reached
+    rate_limit_policy: RateLimitPolicy,
+    /// How requests are sent again after a secondary rate limit or a server error
+    retry_policy: RetryPolicy,
+    /// The cache of responses, for conditional requests
+    cache: Option<Arc<ResponseCache>>,
+    /// The version of the REST API sent with each request, if any
+    api_version: Option<String>,
 }
 
 impl GithubClient {
-    /// Returns a Github client to communicate with the Github API
+    /// Returns a Github client to communicate with the Github API, using Basic authentication
     ///
     /// # Arguments
     ///
@@ -38,9 +399,40 @@ impl GithubClient {
     /// `let github_client = GithubClient::new("k0pernicus", "myapikey0123456789")`
     pub fn new(username: &str, api_key: &str) -> Self {
         GithubClient {
-            username: username.to_owned(),
-            api_key: api_key.to_owned(),
+            username: Some(username.to_owned()),
+            auth: Auth::Basic {
+                username: username.to_owned(),
+                password: api_key.to_owned(),
+            },
+            base_url: GITHUB_API_URL.to_owned(),
+            uploads_url: GITHUB_UPLOADS_URL.to_owned(),
             client: Client::new(),
+            stream_client: stream_client(),
+            installation_token: Mutex::new(None),
+            rate: Mutex::new(None),
+            rate_limit_policy: RateLimitPolicy::default(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            api_version: None,
+        }
+    }
+
+    /// Returns the base URL used to communicate with the Github API
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the base URL used to upload files to the Github API
+    pub fn uploads_url(&self) -> &str {
+        &self.uploads_url
+    }
+
+    /// Returns the rate limit given by the headers of the last response, if any.
+    /// This does not send any request, unlike `RateLimits::get`.
+    pub fn rate(&self) -> Option<Rate> {
+        match self.rate.lock() {
+            Ok(rate) => rate.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
         }
     }
 
@@ -57,9 +449,30 @@ impl GithubClient {
         UserClient::new(&self, username)
     }
 
-    /// Returns a User client that corresponding to the current user
-    pub fn get_myself_client<'a>(&'a self) -> UserClient {
-        UserClient::new(&self, &self.username)
+    /// Returns a client to communicate with the Github API as a Github App
+    pub fn get_app_client<'a>(&'a self) -> AppClient<'a> {
+        AppClient::new(self)
+    }
+
+    /// Returns a User client that corresponding to the current user.
+    /// If the login of the current user is unknown, it is asked to Github.
+    pub fn get_myself_client<'a>(&'a self) -> Result<UserClient<'a>, Error> {
+        if let Some(ref username) = self.username {
+            return Ok(UserClient::myself(self, username));
+        }
+        let response = try!(self.process_request(Method::Get, USER_API_URL, None));
+        match serde_json::from_str::<UserInfoStructure>(&response) {
+            Ok(UserInfoStructure { login: Some(ref login), .. }) => {
+                Ok(UserClient::myself(self, login))
+            }
+            Ok(_) => Err(Error::Auth(String::from("No login for the authenticated user"))),
+            Err(error) => {
+                Err(Error::Deserialize {
+                    error: error,
+                    body: response,
+                })
+            }
+        }
     }
 
     /// Process a request, using an HTTP/HTTPS request method and a URL.
@@ -83,11 +496,443 @@ impl GithubClient {
                            http_method: Method,
                            url: &str,
                            body: Option<String>)
-                           -> Result<String, String> {
-        match self.send_request(http_method, url, &body) {
-            Ok(mut value) => self.get_result_from_request(&mut value),
-            Err(error) => Err(format!("Error processing the request: {}", error)),
+                           -> Result<String, Error> {
+        self.process_request_with_headers(http_method, url, body).map(|(body, _)| body)
+    }
+
+    /// Process a request, like `process_request`, and returns the body of the response converted
+    /// to a given structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    pub fn request_json<T: Deserialize>(&self,
+                                        http_method: Method,
+                                        url: &str,
+                                        body: Option<String>)
+                                        -> Result<T, Error> {
+        self.process_request(http_method, url, body).and_then(decode)
+    }
+
+    /// Process a request with some options, like `request`, and returns the body of the response
+    /// converted to a given structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `options` - The options of the request
+    pub fn request_json_with_options<T: Deserialize>(&self,
+                                                     http_method: Method,
+                                                     url: &str,
+                                                     body: Option<String>,
+                                                     options: &RequestOptions)
+                                                     -> Result<T, Error> {
+        self.request(http_method, url, body, options).and_then(|(body, _)| decode(body))
+    }
+
+    /// Process a request, like `process_request`, and returns the body of the response with its
+    /// headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    pub fn process_request_with_headers(&self,
+                                        http_method: Method,
+                                        url: &str,
+                                        body: Option<String>)
+                                        -> Result<(String, Headers), Error> {
+        self.request(http_method, url, body, &RequestOptions::default())
+    }
+
+    /// Process a request with some options (like the media type to accept, the version of the API
+    /// or extra headers), and returns the body of the response with its headers.
+    /// This is the lower-level method used by all the clients of this crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `options` - The options of the request
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let options = RequestOptions::new().accept("application/vnd.github.v3.html+json");
+    /// let (body, headers) = github_client.request(Method::Get, "repos/k0pernicus/github-api-rs/issues/1", None, &options).unwrap();
+    /// `
+    pub fn request(&self,
+                   http_method: Method,
+                   url: &str,
+                   body: Option<String>,
+                   options: &RequestOptions)
+                   -> Result<(String, Headers), Error> {
+        let authorization = try!(self.authorization());
+        self.execute(http_method, url, &body, &authorization, options)
+    }
+
+    /// Process a request authenticated as the Github App itself (using a JWT), even if the client
+    /// is configured to act as an installation of this Github App.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    pub fn process_app_request(&self,
+                               http_method: Method,
+                               url: &str,
+                               body: Option<String>)
+                               -> Result<String, Error> {
+        let authorization = try!(self.app_authorization());
+        self.execute(http_method, url, &body, &Some(authorization), &RequestOptions::default())
+            .map(|(body, _)| body)
+    }
+
+    /// Returns a page of a list of items.
+    /// The next pages can be requested using `get_next_page`, or all the items can be iterated
+    /// using `get_pages` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice that represent the URL of the list
+    /// * `options` - The pagination parameters (page number and items per page)
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let options = ListOptions { per_page: Some(100), page: None };
+    /// let first_page = github_client.get_page::<Issue>("repos/k0pernicus/github-api-rs/issues", &options);
+    /// `
+    pub fn get_page<T: Deserialize>(&self,
+                                    url: &str,
+                                    options: &ListOptions)
+                                    -> Result<Page<T>, Error> {
+        let url = append_query(url, &options.to_params());
+        let (body, headers) = try!(self.process_request_with_headers(Method::Get, &url, None));
+        Page::from_response(body, &headers)
+    }
+
+    /// Returns the page that follows a given page, if any
+    ///
+    /// # Argument
+    ///
+    /// * `page` - The current page
+    pub fn get_next_page<T: Deserialize>(&self,
+                                         page: &Page<T>)
+                                         -> Option<Result<Page<T>, Error>> {
+        page.links.next.as_ref().map(|next| self.get_page(next, &ListOptions::default()))
+    }
+
+    /// Returns an iterator over all the items of a list, which requests the next pages lazily
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice that represent the URL of the list
+    /// * `options` - The pagination parameters (first page number and items per page)
+    pub fn get_pages<'a, T: Deserialize>(&'a self,
+                                         url: &str,
+                                         options: &ListOptions)
+                                         -> Pages<'a, T> {
+        Pages::new(self,
+                   &append_query(url, &options.to_params()),
+                   RequestOptions::default())
+    }
+
+    /// Returns a page of a list of items, requested as the Github App itself (using a JWT)
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice that represent the URL of the list
+    /// * `options` - The pagination parameters (page number and items per page)
+    pub fn get_app_page<T: Deserialize>(&self,
+                                        url: &str,
+                                        options: &ListOptions)
+                                        -> Result<Page<T>, Error> {
+        let url = append_query(url, &options.to_params());
+        let authorization = try!(self.app_authorization());
+        let (body, headers) = try!(self.execute(Method::Get,
+                                                &url,
+                                                &None,
+                                                &Some(authorization),
+                                                &RequestOptions::default()));
+        Page::from_response(body, &headers)
+    }
+
+    /// Process a request, and returns the response to read as a stream, instead of a body loaded in
+    /// memory (for binary or large contents).
+    /// Redirections are followed, without sending the credentials to another host than the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - A string slice that represent the URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `options` - The options of the request
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut response = github_client.stream(Method::Get, "repos/k0pernicus/github-api-rs/tarball", None, &RequestOptions::new()).unwrap();
+    /// let mut content = Vec::new();
+    /// response.read_to_end(&mut content).unwrap();
+    /// `
+    pub fn stream(&self,
+                  http_method: Method,
+                  url: &str,
+                  body: Option<String>,
+                  options: &RequestOptions)
+                  -> Result<Response, Error> {
+        let authorization = try!(self.authorization());
+        self.with_retries(&http_method, || {
+            self.send_stream_request(http_method.clone(), url, &body, &authorization, options)
+        })
+    }
+
+    /// Downloads the content at a given URL into a writer, and returns the number of bytes
+    /// written.
+    /// The content is streamed, and never entirely loaded in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice that represent the URL of the content
+    /// * `options` - The options of the request (like the media type to accept)
+    /// * `writer` - The destination of the content
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut file = File::create("github-api-rs.tar.gz").unwrap();
+    /// let size = github_client.download("repos/k0pernicus/github-api-rs/tarball", &RequestOptions::new(), &mut file);
+    /// `
+    pub fn download<W: Write>(&self,
+                              url: &str,
+                              options: &RequestOptions,
+                              writer: &mut W)
+                              -> Result<u64, Error> {
+        let mut response = try!(self.stream(Method::Get, url, None, options));
+        copy_response(&mut response, writer)
+    }
+
+    /// Uploads a file to the uploads host of the Github API, streaming its content, and returns
+    /// the body of the response.
+    /// The request is sent once: a failed upload is never retried, as the content cannot be read
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to send the file to, relative to the uploads URL of the client (or
+    /// absolute)
+    /// * `content_type` - The media type of the file, like `application/zip`
+    /// * `reader` - The content of the file
+    /// * `size` - The size of the content, in bytes
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut file = File::open("github-api-rs.zip").unwrap();
+    /// let size = file.metadata().unwrap().len();
+    /// let body = github_client.upload("repos/k0pernicus/github-api-rs/releases/1/assets?name=github-api-rs.zip", "application/zip", &mut file, size);
+    /// `
+    pub fn upload(&self,
+                  url: &str,
+                  content_type: &str,
+                  reader: &mut Read,
+                  size: u64)
+                  -> Result<String, Error> {
+        let authorization = try!(self.authorization());
+        let url = if url.starts_with("http://") || url.starts_with("https://") {
+            url.to_owned()
+        } else {
+            format!("{}/{}", self.uploads_url, url.trim_left_matches('/'))
+        };
+        let url = append_query(&url, &self.auth.query());
+        let mut headers = self.request_headers(&authorization, &RequestOptions::default(), None);
+        headers.set_raw(CONTENT_TYPE_HEADER, vec![content_type.as_bytes().to_vec()]);
+        let mut response = try!(self.client
+            .request(Method::Post, &url)
+            .headers(headers)
+            .body(Body::SizedBody(reader, size))
+            .send());
+        self.update_rate(&response.headers);
+        self.get_result_from_request(&mut response)
+    }
+
+    /// Send a request, and returns the body of the response with its headers, or an error.
+    /// If the rate limit has been reached, the rate limit policy of the client is applied.
+    /// Secondary rate limits, server errors and connection errors are handled using the retry
+    /// policy of the client, that does not count the time waited for the rate limit to be reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - The URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `authorization` - The value of the Authorization header, if any
+    /// * `options` - The options of the request
+    fn execute(&self,
+               http_method: Method,
+               url: &str,
+               body: &Option<String>,
+               authorization: &Option<String>,
+               options: &RequestOptions)
+               -> Result<(String, Headers), Error> {
+        // Only responses to GET requests are cached, for each media type and version of the API
+        let cache = match http_method {
+            Method::Get => self.cache.as_ref(),
+            _ => None,
+        };
+        let cache_url = match (&options.accept, self.api_version(options)) {
+            (&None, None) => self.build_url(url),
+            (accept, version) => {
+                format!("{} {} {}",
+                        accept.as_ref().map_or(DEFAULT_MEDIA_TYPE, |accept| accept.as_str()),
+                        version.unwrap_or(""),
+                        self.build_url(url))
+            }
+        };
+        let cached = cache.and_then(|cache| cache.get(&cache_url));
+        self.with_retries(&http_method, || {
+            let mut response = try!(self.send_request(http_method.clone(),
+                                                      url,
+                                                      body,
+                                                      authorization,
+                                                      options,
+                                                      cached.as_ref()));
+            self.update_rate(&response.headers);
+            if let Some(ref cached) = cached {
+                if response.status == StatusCode::NotModified {
+                    return Ok((cached.body.clone(), cached.merge_headers(&response.headers)));
+                }
+            }
+            let body = try!(self.get_result_from_request(&mut response));
+            if let Some(cache) = cache {
+                if let Some(cached) = CachedResponse::from_response(&body, &response.headers) {
+                    cache.set(&cache_url, cached);
+                }
+            }
+            Ok((body, response.headers.clone()))
+        })
+    }
+
+    /// Calls a function that sends a request until it succeeds, or until the retry policy of the
+    /// client gives up.
+    /// If the rate limit has been reached, the rate limit policy of the client is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP/HTTPS method of the request
+    /// * `send` - The function that sends the request
+    fn with_retries<T, F>(&self, http_method: &Method, mut send: F) -> Result<T, Error>
+        where F: FnMut() -> Result<T, Error>
+    {
+        let mut attempt = 1;
+        let mut waited = Duration::from_secs(0);
+        let mut rate_limit_waited = Duration::from_secs(0);
+        loop {
+            let result = send();
+            let delay = match result {
+                Ok(value) => return Ok(value),
+                Err(Error::RateLimitExceeded { reset }) => {
+                    let rate = self.rate().unwrap_or_default();
+                    let delay =
+                        self.rate_limit_policy.delay(&rate, reset, rate_limit_waited, app::now());
+                    if let Some(delay) = delay {
+                        rate_limit_waited = rate_limit_waited + delay;
+                    }
+                    delay
+                }
+                Err(ref error) => {
+                    let delay = self.retry_policy.delay(http_method, attempt, waited, error);
+                    if let Some(delay) = delay {
+                        waited = waited + delay;
+                        attempt += 1;
+                    }
+                    delay
+                }
+            };
+            match delay {
+                Some(delay) => thread::sleep(delay),
+                None => return result,
+            }
+        }
+    }
+
+    /// Returns the version of the REST API to send for a request, if any
+    fn api_version<'b>(&'b self, options: &'b RequestOptions) -> Option<&'b str> {
+        options.api_version.as_ref().or(self.api_version.as_ref()).map(|version| version.as_str())
+    }
+
+    /// Saves the rate limit given by the headers of a response, if any
+    fn update_rate(&self, headers: &Headers) {
+        if let Some(new_rate) = Rate::from_headers(headers) {
+            let mut rate = match self.rate.lock() {
+                Ok(rate) => rate,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            *rate = Some(new_rate);
+        }
+    }
+
+    /// Returns the value of the Authorization header to authenticate as the Github App itself
+    fn app_authorization(&self) -> Result<String, Error> {
+        match self.auth {
+            Auth::App { app_id, ref private_key, .. } => {
+                Ok(format!("Bearer {}", try!(app::create_jwt(app_id, private_key))))
+            }
+            _ => Err(Error::Auth(String::from("The client is not authenticated as a Github App"))),
+        }
+    }
+
+    /// Returns the value of the Authorization header to send, if requests are authenticated by
+    /// this header.
+    /// For a Github App installation, the access token is created (or refreshed) if needed.
+    fn authorization(&self) -> Result<Option<String>, Error> {
+        Ok(match self.auth {
+            Auth::Anonymous |
+            Auth::OAuth { .. } => None,
+            Auth::Token(ref token) => Some(format!("token {}", token)),
+            Auth::Bearer(ref token) => Some(format!("Bearer {}", token)),
+            Auth::Basic { ref username, ref password } => {
+                let credentials = format!("{}:{}", username, password);
+                Some(format!("Basic {}", credentials.as_bytes().to_base64(STANDARD)))
+            }
+            Auth::App { app_id, ref private_key, installation_id: None } => {
+                Some(format!("Bearer {}", try!(app::create_jwt(app_id, private_key))))
+            }
+            Auth::App { installation_id: Some(installation_id), .. } => {
+                Some(format!("token {}", try!(self.installation_token(installation_id))))
+            }
+        })
+    }
+
+    /// Returns a valid access token for a given installation of the Github App.
+    /// The token is cached, and refreshed shortly before it expires.
+    ///
+    /// # Argument
+    ///
+    /// * `installation_id` - The identifier of the installation
+    fn installation_token(&self, installation_id: usize) -> Result<String, Error> {
+        let mut cache = match self.installation_token.lock() {
+            Ok(cache) => cache,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some((ref token, refresh_at)) = *cache {
+            if app::now() < refresh_at {
+                return Ok(token.token.clone());
+            }
         }
+        let token = try!(self.get_app_client().create_installation_token(installation_id));
+        let refresh_at = token.refresh_at();
+        let value = token.token.clone();
+        *cache = Some((token, refresh_at));
+        Ok(value)
     }
 
     /// Send an HTTP/HTTPS request over Hyper, to a given URL.
@@ -98,19 +943,20 @@ impl GithubClient {
     /// * `method` - An HTTP/HTTPS request method
     /// * `url` - The URL to send the request
     /// * `body` - A structure to send, with the request
+    /// * `authorization` - The value of the Authorization header, if any
+    /// * `options` - The options of the request
+    /// * `cached` - The response saved in cache for this request, to send a conditional request
     fn send_request(&self,
                     method: Method,
                     url: &str,
-                    body: &Option<String>)
-                    -> Result<Response, Error> {
-        let url = format!("{}/{}", GITHUB_API_URL, url);
-        let mut request = self.client
-            .request(method, &url)
-            .header(Authorization(Basic {
-                username: self.username.to_owned(),
-                password: Some(self.api_key.to_owned()),
-            }))
-            .header(UserAgent(USER_AGENT.to_owned()));
+                    body: &Option<String>,
+                    authorization: &Option<String>,
+                    options: &RequestOptions,
+                    cached: Option<&CachedResponse>)
+                    -> Result<Response, ::hyper::Error> {
+        let url = append_query(&self.build_url(url), &self.auth.query());
+        let headers = self.request_headers(authorization, options, cached);
+        let mut request = self.client.request(method, &url).headers(headers);
         // If a body has been send, give to the request a body
         match body {
             // Add the body, which contains the message (structure) to send
@@ -120,53 +966,203 @@ impl GithubClient {
         request.send()
     }
 
+    /// Returns the headers to send with a request to the Github API
+    ///
+    /// # Arguments
+    ///
+    /// * `authorization` - The value of the Authorization header, if any
+    /// * `options` - The options of the request
+    /// * `cached` - The response saved in cache for this request, to send a conditional request
+    fn request_headers(&self,
+                       authorization: &Option<String>,
+                       options: &RequestOptions,
+                       cached: Option<&CachedResponse>)
+                       -> Headers {
+        let mut headers = Headers::new();
+        headers.set(UserAgent(USER_AGENT.to_owned()));
+        if let &Some(ref authorization) = authorization {
+            headers.set(Authorization(authorization.to_owned()));
+        }
+        let accept = options.accept.as_ref().map_or(DEFAULT_MEDIA_TYPE, |accept| accept.as_str());
+        headers.set_raw(ACCEPT_HEADER, vec![accept.as_bytes().to_vec()]);
+        if let Some(version) = self.api_version(options) {
+            headers.set_raw(API_VERSION_HEADER, vec![version.as_bytes().to_vec()]);
+        }
+        // Ask Github to answer "304 Not Modified" if the saved response is still valid
+        if let Some(cached) = cached {
+            if let Some(ref etag) = cached.etag {
+                headers.set_raw(IF_NONE_MATCH_HEADER, vec![etag.clone().into_bytes()]);
+            }
+            if let Some(ref last_modified) = cached.last_modified {
+                headers.set_raw(IF_MODIFIED_SINCE_HEADER, vec![last_modified.clone().into_bytes()]);
+            }
+        }
+        for &(ref name, ref value) in &options.headers {
+            headers.set_raw(name.clone(), vec![value.clone().into_bytes()]);
+        }
+        headers
+    }
+
+    /// Send a request whose response is streamed, following redirections by hand: the
+    /// credentials are only sent to the host of the API, and not to the host that serves the
+    /// content (like archives or release assets).
+    /// A "307 Temporary Redirect" or a "308 Permanent Redirect" is followed with the same method
+    /// and body, the other redirections with a GET request without body.
+    /// The response is returned once its status has been checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An HTTP/HTTPS request method
+    /// * `url` - The URL to send the request
+    /// * `body` - A structure to send, with the request
+    /// * `authorization` - The value of the Authorization header, if any
+    /// * `options` - The options of the request
+    fn send_stream_request(&self,
+                           method: Method,
+                           url: &str,
+                           body: &Option<String>,
+                           authorization: &Option<String>,
+                           options: &RequestOptions)
+                           -> Result<Response, Error> {
+        let mut url = self.build_url(url);
+        let mut request_url = append_query(&url, &self.auth.query());
+        let mut headers = self.request_headers(authorization, options, None);
+        let mut method = method;
+        let mut body = body.as_ref();
+        let mut redirects = 0;
+        loop {
+            let mut request = self.stream_client
+                .request(method.clone(), &request_url)
+                .headers(headers);
+            if let Some(body) = body {
+                request = request.body(body);
+            }
+            let mut response = try!(request.send());
+            let location = if response.status.class() == StatusClass::Redirection &&
+                              redirects < MAX_REDIRECTS {
+                response.headers.get::<Location>().map(|location| location.0.clone())
+            } else {
+                None
+            };
+            let location = match location {
+                Some(location) => location,
+                None => {
+                    self.update_rate(&response.headers);
+                    try!(self.check_response(&mut response));
+                    return Ok(response);
+                }
+            };
+            match response.status.to_u16() {
+                307 | 308 => (),
+                _ => {
+                    method = Method::Get;
+                    body = None;
+                }
+            }
+            url = match Url::parse(&url).and_then(|base| base.join(&location)) {
+                Ok(next) => next.into_string(),
+                Err(_) => return Err(Error::InvalidUrl(location)),
+            };
+            request_url = url.clone();
+            headers = if same_host(&url, &self.base_url) {
+                self.request_headers(authorization, options, None)
+            } else {
+                let mut headers = Headers::new();
+                headers.set(UserAgent(USER_AGENT.to_owned()));
+                headers
+            };
+            redirects += 1;
+        }
+    }
+
+    /// Returns the full URL to request.
+    /// Absolute URLs (like the ones given by Github in responses) are kept as is, and others are
+    /// relative to the base URL of the client.
+    ///
+    /// # Argument
+    ///
+    /// * `url` - A string slice that holds an absolute URL, or a path relative to the base URL
+    fn build_url(&self, url: &str) -> String {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            url.to_owned()
+        } else {
+            format!("{}/{}", self.base_url, url.trim_left_matches('/'))
+        }
+    }
+
     /// Return a Result type that contains the body of the request response, or an error.
     ///
     /// # Argument
     ///
     /// * `response` - A reference from a mutable request Response type
-    fn get_result_from_request(&self, response: &mut Response) -> Result<String, String> {
+    fn get_result_from_request(&self, response: &mut Response) -> Result<String, Error> {
+        try!(self.check_response(response));
         let mut body = String::new();
-        match response.read_to_string(&mut body) {
-            Ok(_) => {}
-            Err(error) => return Err(format!("Error processing the response request: {}", error)),
-        }
+        try!(response.read_to_string(&mut body));
+        Ok(body)
+    }
+
+    /// Returns an error if the status of a response is not successful, built from the body of the
+    /// response.
+    /// The body of a successful response is not read.
+    ///
+    /// # Argument
+    ///
+    /// * `response` - A reference from a mutable request Response type
+    fn check_response(&self, response: &mut Response) -> Result<(), Error> {
         match response.status.class().default_code() {
-            StatusCode::Ok => Ok(body),
+            StatusCode::Ok => Ok(()),
             _ => {
-                match serde_json::from_str::<GitHubErrorResult>(&body) {
-                    Ok(error) => Err(error.message),
-                    Err(error) => {
-                        Err(format!("Error processing Github error (code status {:?}): {}\nBody: \
-                                     {}",
-                                    response.status.class().default_code(),
-                                    error,
-                                    body))
-                    }
+                let mut body = String::new();
+                try!(response.read_to_string(&mut body));
+                // Github answers 403 (or 429) with no remaining request when the rate limit is reached
+                let remaining = response.headers.get::<XRateLimitRemaining>().map(|value| value.0);
+                if remaining == Some(0) &&
+                   (response.status == StatusCode::Forbidden ||
+                    response.status == StatusCode::TooManyRequests) {
+                    let reset = response.headers.get::<XRateLimitReset>().map(|value| value.0);
+                    return Err(Error::RateLimitExceeded { reset: reset });
+                }
+                let error = parse_error(body);
+                // Github answers 403 (or 429) with a Retry-After header, or a message about
+                // secondary rate limits (formerly abuse detection), when requests are sent too fast
+                let retry_after = response.headers
+                    .get::<RetryAfter>()
+                    .map(|value| value.delay(app::now()));
+                let message = error.message.to_lowercase();
+                if (response.status == StatusCode::Forbidden ||
+                    response.status == StatusCode::TooManyRequests) &&
+                   (retry_after.is_some() || message.contains("secondary rate limit") ||
+                    message.contains("abuse")) {
+                    return Err(Error::SecondaryRateLimitExceeded {
+                        status: response.status,
+                        retry_after: retry_after,
+                    });
                 }
+                Err(Error::Api {
+                    status: response.status,
+                    error: error,
+                })
             }
         }
     }
 }
 
-/// A private structure to get and process errors from Github
-#[derive(Debug, Deserialize)]
-struct GitHubErrorResult {
-    /// The global error message
-    message: String,
-    /// All detected errors
-    #[serde(default)]
-    errors: Vec<GitHubErrorPart>,
+/// Returns the error sent back by Github in the body of a response
+///
+/// # Argument
+///
+/// * `body` - The body of the response
+fn parse_error(body: String) -> GitHubErrorResult {
+    match serde_json::from_str::<GitHubErrorResult>(&body) {
+        Ok(error) => error,
+        // Keep the raw body as the error message if Github did not send a JSON error
+        Err(_) => {
+            GitHubErrorResult {
+                message: body,
+                errors: Vec::new(),
+                documentation_url: None,
+            }
+        }
+    }
 }
-
-/// A private structure to parse an error from Github
-#[derive(Debug, Deserialize)]
-struct GitHubErrorPart {
-    /// The field that contains the error
-    field: String,
-    /// The code that contains the error
-    code: String,
-    /// The message to understand the error
-    #[serde(default)]
-    message: String,
-}
\ No newline at end of file
@@ -0,0 +1,408 @@
+///
+/// Module to compose with multi-file commits, built on top of the git database API.
+/// Github documentation available at https://developer.github.com/v3/git/.
+///
+use commits::{CommitAuthor, GitCommit};
+use error::Error;
+use git::{FileMode, GitClient, NewBlob, NewCommit, NewTree, NewTreeEntry, ObjectType, TreeEntry};
+use hyper::status::StatusCode;
+use std::collections::HashMap;
+
+/// A change of a file (or a directory) to commit
+enum Change {
+    /// Writes a file, that must already exist or not
+    Write {
+        path: String,
+        content: Vec<u8>,
+        must_exist: bool,
+    },
+    /// Deletes a file or a directory
+    Delete { path: String },
+    /// Moves a file or a directory
+    Rename { from: String, to: String },
+}
+
+/// A builder to commit a set of file changes to a branch in a single commit.
+///
+/// The commit is created with the git database API: the blobs are created first, then the
+/// sequence "get the branch, get its commit, create the tree, create the commit, update the
+/// branch" is performed.
+/// The branch is never force-updated: if it has moved in the meantime (the update is not a
+/// fast-forward anymore), the sequence is performed again on top of the new head of the branch.
+///
+/// Changes are applied in order, and a path can be changed several times (like a file added, then
+/// renamed). A directory cannot be moved or deleted in the same commit as the paths it contains.
+pub struct CommitBuilder<'a> {
+    /// The git data client of the repository
+    git: GitClient<'a>,
+    /// The branch to commit to
+    branch: String,
+    /// The commit message
+    message: String,
+    /// The changes to commit, in order
+    changes: Vec<Change>,
+    /// The author of the commit, if not the authenticated user
+    author: Option<CommitAuthor>,
+    /// The committer of the commit, if not the author
+    committer: Option<CommitAuthor>,
+    /// The number of attempts to update the branch
+    max_attempts: usize,
+}
+
+impl<'a> CommitBuilder<'a> {
+    /// Returns a builder to commit changes to a branch
+    ///
+    /// # Arguments
+    ///
+    /// * `git` - The git data client of the repository
+    /// * `branch` - The branch to commit to, like `master`
+    /// * `message` - The commit message
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let commit = repo_client.commit_builder("master", "Update the configuration")
+    ///     .modify("config/app.toml", b"debug = false")
+    ///     .rename("config/old.toml", "config/legacy.toml")
+    ///     .delete("config/tmp.toml")
+    ///     .commit();
+    /// `
+    pub fn new(git: GitClient<'a>, branch: &str, message: &str) -> Self {
+        CommitBuilder {
+            git: git,
+            branch: branch.trim_left_matches("refs/heads/").to_owned(),
+            message: message.to_owned(),
+            changes: Vec::new(),
+            author: None,
+            committer: None,
+            max_attempts: 3,
+        }
+    }
+
+    /// Adds a file (or replaces it, if it already exists)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `content` - The content of the file
+    pub fn add(mut self, path: &str, content: &[u8]) -> Self {
+        self.changes.push(Change::Write {
+            path: path.trim_matches('/').to_owned(),
+            content: content.to_vec(),
+            must_exist: false,
+        });
+        self
+    }
+
+    /// Modifies an existing file, keeping its mode (the commit fails if the file does not exist)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `content` - The new content of the file
+    pub fn modify(mut self, path: &str, content: &[u8]) -> Self {
+        self.changes.push(Change::Write {
+            path: path.trim_matches('/').to_owned(),
+            content: content.to_vec(),
+            must_exist: true,
+        });
+        self
+    }
+
+    /// Deletes an existing file or directory (the commit fails if it does not exist)
+    ///
+    /// # Argument
+    ///
+    /// * `path` - The path of the file or of the directory, in the repository
+    pub fn delete(mut self, path: &str) -> Self {
+        self.changes.push(Change::Delete { path: path.trim_matches('/').to_owned() });
+        self
+    }
+
+    /// Moves an existing file or directory (the commit fails if it does not exist)
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current path, in the repository
+    /// * `to` - The new path, in the repository
+    pub fn rename(mut self, from: &str, to: &str) -> Self {
+        self.changes.push(Change::Rename {
+            from: from.trim_matches('/').to_owned(),
+            to: to.trim_matches('/').to_owned(),
+        });
+        self
+    }
+
+    /// Sets the author of the commit (the authenticated user by default)
+    pub fn author(mut self, author: CommitAuthor) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the committer of the commit (the author by default)
+    pub fn committer(mut self, committer: CommitAuthor) -> Self {
+        self.committer = Some(committer);
+        self
+    }
+
+    /// Sets the number of attempts to update the branch, when it moves during the commit
+    /// (3 by default)
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Commits the changes, and returns the created commit once the branch points to it
+    pub fn commit(&self) -> Result<GitCommit, Error> {
+        // The blobs do not depend on the head of the branch: they are only created once
+        let mut blobs = Vec::new();
+        for change in &self.changes {
+            if let Change::Write { ref content, .. } = *change {
+                let blob = try!(self.git.create_blob(&NewBlob::base64(content)));
+                blobs.push(try!(required(blob.sha, "blob")));
+            }
+        }
+        // Each segment of the reference (like `heads/release/#2`) is encoded by the git client
+        let reference = format!("heads/{}", self.branch);
+        let mut attempt = 1;
+        loop {
+            let head = try!(self.git.get_ref(&reference));
+            let head_sha = try!(required(head.object.and_then(|object| object.sha), "branch"));
+            let head_commit = try!(self.git.get_commit(&head_sha));
+            let base_tree = try!(required(head_commit.tree.and_then(|tree| tree.sha), "tree"));
+            let entries = try!(self.tree_entries(&base_tree, &blobs));
+            let tree = try!(self.git.create_tree(&NewTree {
+                base_tree: Some(base_tree),
+                tree: entries,
+            }));
+            let commit = try!(self.git.create_commit(&NewCommit {
+                message: self.message.clone(),
+                tree: try!(required(tree.sha, "tree")),
+                parents: vec![head_sha],
+                author: self.author.clone(),
+                committer: self.committer.clone(),
+                signature: None,
+            }));
+            let commit_sha = try!(required(commit.sha.clone(), "commit"));
+            match self.git.update_ref(&reference, &commit_sha, false) {
+                Ok(_) => return Ok(commit),
+                Err(ref error) if attempt < self.max_attempts && is_ref_conflict(error) => {
+                    attempt += 1
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Returns the entries of the new tree, that correspond to the changes
+    ///
+    /// # Arguments
+    ///
+    /// * `base_tree` - The SHA of the tree of the head of the branch
+    /// * `blobs` - The SHAs of the blobs of the written files, in order
+    fn tree_entries(&self, base_tree: &str, blobs: &[String]) -> Result<Vec<NewTreeEntry>, Error> {
+        let mut tree = PendingTree::new(&self.git, base_tree);
+        let mut blobs = blobs.iter();
+        for change in &self.changes {
+            match *change {
+                Change::Write { ref path, must_exist, .. } => {
+                    let mode = match try!(tree.find(path)) {
+                        Some((mode, _, _)) => mode,
+                        None if must_exist => return Err(self.missing(path)),
+                        None => FileMode::File,
+                    };
+                    let sha = try!(required(blobs.next().cloned(), "blob"));
+                    try!(tree.set(path, Some((mode, ObjectType::Blob, sha))));
+                }
+                Change::Delete { ref path } => {
+                    try!(try!(tree.find(path)).ok_or_else(|| self.missing(path)));
+                    try!(tree.set(path, None));
+                }
+                Change::Rename { ref from, ref to } => {
+                    let entry = try!(try!(tree.find(from)).ok_or_else(|| self.missing(from)));
+                    try!(tree.set(from, None));
+                    try!(tree.set(to, Some(entry)));
+                }
+            }
+        }
+        Ok(tree.entries())
+    }
+
+    /// Returns the error for a path that does not exist on the branch
+    fn missing(&self, path: &str) -> Error {
+        Error::Commit(format!("{} does not exist on the branch {}", path, self.branch))
+    }
+}
+
+/// Returns true if the update of a reference has been rejected because the reference has moved
+fn is_ref_conflict(error: &Error) -> bool {
+    match error.status() {
+        Some(StatusCode::UnprocessableEntity) |
+        Some(StatusCode::Conflict) => true,
+        _ => false,
+    }
+}
+
+/// Returns a SHA sent back by Github, or an error if it is missing
+fn required(sha: Option<String>, object: &str) -> Result<String, Error> {
+    sha.ok_or_else(|| Error::Commit(format!("Github did not send the SHA of the {}", object)))
+}
+
+/// The mode, the type and the SHA of an entry of a tree
+type Entry = (FileMode, ObjectType, String);
+
+/// A path changed by a commit
+struct PendingPath {
+    /// The path, in the repository
+    path: String,
+    /// The mode and the type of the entry at this path in the base tree, if any
+    base: Option<(FileMode, ObjectType)>,
+    /// The new entry at this path, or None if the path is deleted
+    entry: Option<Entry>,
+}
+
+/// The changes applied to a base tree: each changed path is sent to Github once, with its final
+/// entry
+struct PendingTree<'g, 'a: 'g> {
+    /// The entries of the base tree
+    lookup: TreeLookup<'g, 'a>,
+    /// The changed paths, in the order of their first change
+    paths: Vec<PendingPath>,
+}
+
+impl<'g, 'a> PendingTree<'g, 'a> {
+    fn new(git: &'g GitClient<'a>, base_tree: &str) -> Self {
+        PendingTree {
+            lookup: TreeLookup::new(git, base_tree),
+            paths: Vec::new(),
+        }
+    }
+
+    /// Returns the entry at a given path, once the previous changes are applied
+    fn find(&mut self, path: &str) -> Result<Option<Entry>, Error> {
+        try!(self.check_overlap(path));
+        if let Some(pending) = self.paths.iter().find(|pending| pending.path == path) {
+            return Ok(pending.entry.clone());
+        }
+        self.lookup.find(path)
+    }
+
+    /// Changes the entry at a given path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path, in the repository
+    /// * `entry` - The new entry, or None to delete the path
+    fn set(&mut self, path: &str, entry: Option<Entry>) -> Result<(), Error> {
+        try!(self.check_overlap(path));
+        if let Some(pending) = self.paths.iter_mut().find(|pending| pending.path == path) {
+            pending.entry = entry;
+            return Ok(());
+        }
+        let base = try!(self.lookup.find(path)).map(|(mode, object_type, _)| (mode, object_type));
+        self.paths.push(PendingPath {
+            path: path.to_owned(),
+            base: base,
+            entry: entry,
+        });
+        Ok(())
+    }
+
+    /// Returns an error if a path is inside a changed directory, or contains a changed path, as
+    /// Github would receive conflicting entries
+    fn check_overlap(&self, path: &str) -> Result<(), Error> {
+        match self.paths
+            .iter()
+            .find(|pending| is_inside(path, &pending.path) || is_inside(&pending.path, path)) {
+            Some(pending) => {
+                Err(Error::Commit(format!("{} and {} cannot be changed in the same commit",
+                                          pending.path,
+                                          path)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the entries of the tree to create
+    fn entries(self) -> Vec<NewTreeEntry> {
+        self.paths
+            .into_iter()
+            .filter_map(|pending| match (pending.entry, pending.base) {
+                (Some((mode, object_type, sha)), _) => {
+                    Some(NewTreeEntry {
+                        path: pending.path,
+                        mode: mode,
+                        object_type: object_type,
+                        sha: Some(Some(sha)),
+                        content: None,
+                    })
+                }
+                (None, Some((mode, object_type))) => {
+                    Some(NewTreeEntry {
+                        path: pending.path,
+                        mode: mode,
+                        object_type: object_type,
+                        sha: Some(None),
+                        content: None,
+                    })
+                }
+                // A path created then deleted by the commit does not exist in the base tree
+                (None, None) => None,
+            })
+            .collect()
+    }
+}
+
+/// Returns true if a path is inside a directory (and is not the directory itself)
+fn is_inside(path: &str, directory: &str) -> bool {
+    path.starts_with(directory) && path[directory.len()..].starts_with('/')
+}
+
+/// Finds the entries of a tree by their path, getting each subtree only once
+struct TreeLookup<'g, 'a: 'g> {
+    /// The git data client of the repository
+    git: &'g GitClient<'a>,
+    /// The SHA of the root tree
+    root: String,
+    /// The entries of the trees already fetched, by SHA
+    trees: HashMap<String, Vec<TreeEntry>>,
+}
+
+impl<'g, 'a> TreeLookup<'g, 'a> {
+    fn new(git: &'g GitClient<'a>, root: &str) -> Self {
+        TreeLookup {
+            git: git,
+            root: root.to_owned(),
+            trees: HashMap::new(),
+        }
+    }
+
+    /// Returns the mode, the type and the SHA of the entry at a given path, if it exists
+    fn find(&mut self, path: &str) -> Result<Option<(FileMode, ObjectType, String)>, Error> {
+        let segments: Vec<&str> = path.split('/').collect();
+        let mut tree_sha = self.root.clone();
+        for (index, segment) in segments.iter().enumerate() {
+            if !self.trees.contains_key(&tree_sha) {
+                let tree = try!(self.git.get_tree(&tree_sha, false));
+                self.trees.insert(tree_sha.clone(), tree.tree);
+            }
+            let found = self.trees[&tree_sha]
+                .iter()
+                .find(|entry| entry.path.as_ref().map(|path| path.as_str()) == Some(*segment))
+                .map(|entry| (entry.mode, entry.object_type, entry.sha.clone()));
+            match found {
+                Some((Some(mode), Some(object_type), Some(sha))) => {
+                    if index + 1 == segments.len() {
+                        return Ok(Some((mode, object_type, sha)));
+                    }
+                    if object_type != ObjectType::Tree {
+                        return Ok(None);
+                    }
+                    tree_sha = sha;
+                }
+                _ => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,367 @@
+///
+/// Module to compose with commits.
+/// Github documentation available at https://developer.github.com/v3/repos/commits/.
+///
+use checks::CheckRunList;
+use client::{GithubClient, append_query, encode_segment};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use statuses::CombinedStatus;
+use user::UserInfoStructure;
+
+/// Structure that represents a commits client.
+/// This structure is needed to communicate with the Github API, about the commits of a
+/// repository.
+pub struct CommitsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> CommitsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the commits of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let commits_client = CommitsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        CommitsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the commits of the repository that match some filters, the most recent first
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list(&self, filters: &CommitListOptions, options: &ListOptions) -> Pages<'a, Commit> {
+        let url = append_query(&format!("{}/commits", self.url()), &filters.to_params());
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a commit, with its changed files and its stats
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    pub fn get(&self, reference: &str) -> Result<Commit, Error> {
+        let url = format!("{}/commits/{}", self.url(), encode_segment(reference));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Compares two commits (`base...head`), and returns the commits and the files changed from
+    /// their merge base to the head
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base branch, tag or commit SHA
+    /// * `head` - The head branch, tag or commit SHA (as `user:branch` for a fork)
+    ///
+    /// # Example
+    ///
+    /// `let comparison = commits_client.compare("v1.0.0", "master");`
+    pub fn compare(&self, base: &str, head: &str) -> Result<Comparison, Error> {
+        let url = format!("{}/compare/{}...{}",
+                          self.url(),
+                          encode_segment(base),
+                          encode_segment(head));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the combined status of a commit: the latest status of each context, and the
+    /// resulting state
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    pub fn combined_status(&self, reference: &str) -> Result<CombinedStatus, Error> {
+        let url = format!("{}/commits/{}/status", self.url(), encode_segment(reference));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the check runs of a commit
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    /// * `options` - The pagination parameters
+    pub fn check_runs(&self, reference: &str, options: &ListOptions) -> Result<CheckRunList, Error> {
+        let url = format!("{}/commits/{}/check-runs", self.url(), encode_segment(reference));
+        let url = append_query(&url, &options.to_params());
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+}
+
+/// Filters to list the commits of a repository
+#[derive(Debug, Clone, Default)]
+pub struct CommitListOptions {
+    /// The SHA or the branch to start listing the commits from (the default branch if `None`)
+    pub sha: Option<String>,
+    /// Only the commits that change this file path
+    pub path: Option<String>,
+    /// Only the commits of this author (login or email address)
+    pub author: Option<String>,
+    /// Only the commits after this time (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    pub since: Option<String>,
+    /// Only the commits before this time (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    pub until: Option<String>,
+}
+
+impl CommitListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(ref sha) = self.sha {
+            params.push(("sha", sha.to_owned()));
+        }
+        if let Some(ref path) = self.path {
+            params.push(("path", path.to_owned()));
+        }
+        if let Some(ref author) = self.author {
+            params.push(("author", author.to_owned()));
+        }
+        if let Some(ref since) = self.since {
+            params.push(("since", since.to_owned()));
+        }
+        if let Some(ref until) = self.until {
+            params.push(("until", until.to_owned()));
+        }
+        params
+    }
+}
+
+/// The status of a file changed by a commit, or by a pull request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FileStatus {
+    #[serde(rename = "added")]
+    Added,
+    #[serde(rename = "removed")]
+    Removed,
+    #[serde(rename = "modified")]
+    Modified,
+    #[serde(rename = "renamed")]
+    Renamed,
+    #[serde(rename = "copied")]
+    Copied,
+    #[serde(rename = "changed")]
+    Changed,
+    #[serde(rename = "unchanged")]
+    Unchanged,
+}
+
+/// Contains all necessary fields to define a file changed by a commit, or by a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiffFile {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<FileStatus>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub additions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deletions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub changes: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub blob_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub raw_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub contents_url: Option<String>,
+    /// The unified diff of the file (missing for binary or too large files)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub patch: Option<String>,
+    /// The previous path of a renamed file
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub previous_filename: Option<String>,
+}
+
+/// The author, or the committer, of a commit in git
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitAuthor {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub email: Option<String>,
+    /// ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub date: Option<String>,
+}
+
+/// A reference to a git object (a tree, or a parent commit)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ObjectRef {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+}
+
+/// The git data of a commit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommitDetails {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<CommitAuthor>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<CommitAuthor>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tree: Option<ObjectRef>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comment_count: Option<usize>,
+}
+
+/// The number of lines changed by a commit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommitStats {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub additions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deletions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total: Option<usize>,
+}
+
+/// Contains all necessary fields to define a commit of a repository
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Commit {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit: Option<CommitDetails>,
+    /// The Github user that corresponds to the author of the commit, if any
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<UserInfoStructure>,
+    /// The Github user that corresponds to the committer of the commit, if any
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub parents: Vec<ObjectRef>,
+    /// Only returned for a single commit
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub stats: Option<CommitStats>,
+    /// Only returned for a single commit
+    #[serde(default)]
+    pub files: Vec<DiffFile>,
+}
+
+/// The verification of the signature of a commit (or of a tag)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Verification {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub verified: Option<bool>,
+    /// The reason of the verification result, like `valid` or `unsigned`
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub payload: Option<String>,
+}
+
+/// Contains all necessary fields to define a commit object of the git database
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitCommit {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<CommitAuthor>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<CommitAuthor>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tree: Option<ObjectRef>,
+    #[serde(default)]
+    pub parents: Vec<ObjectRef>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub verification: Option<Verification>,
+}
+
+/// The position of the head of a comparison, relatively to its base
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ComparisonStatus {
+    #[serde(rename = "identical")]
+    Identical,
+    #[serde(rename = "ahead")]
+    Ahead,
+    #[serde(rename = "behind")]
+    Behind,
+    #[serde(rename = "diverged")]
+    Diverged,
+}
+
+/// Contains all necessary fields to define the comparison of two commits
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Comparison {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub permalink_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub diff_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub patch_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub base_commit: Option<Commit>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merge_base_commit: Option<Commit>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<ComparisonStatus>,
+    /// The number of commits of the head that are not in the base
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub ahead_by: Option<usize>,
+    /// The number of commits of the base that are not in the head
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub behind_by: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_commits: Option<usize>,
+    /// The commits from the merge base to the head (at most 250 commits)
+    #[serde(default)]
+    pub commits: Vec<Commit>,
+    /// The files changed from the merge base to the head (at most 300 files)
+    #[serde(default)]
+    pub files: Vec<DiffFile>,
+}
@@ -0,0 +1,320 @@
+///
+/// Module to compose with the contents of a repository.
+/// Github documentation available at https://developer.github.com/v3/repos/contents/.
+///
+use client::{GithubClient, RequestOptions, append_query, decode, encode, encode_segment};
+use commits::{CommitAuthor, GitCommit};
+use error::Error;
+use hyper::method::Method;
+use repo::REPOS_API_URL;
+use rustc_serialize::base64::{FromBase64, STANDARD, ToBase64};
+use std::io::Write;
+
+/// The media type to accept, to get the raw content of a file
+const RAW_MEDIA_TYPE: &'static str = "application/vnd.github.v3.raw";
+
+/// Structure that represents a contents client.
+/// This structure is needed to communicate with the Github API, about the files and directories
+/// of a repository.
+pub struct ContentsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> ContentsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the contents of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let contents_client = ContentsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        ContentsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of a path of the repository, with the given git reference if any.
+    /// Each segment of the path is percent-encoded, the slashes are kept.
+    fn url(&self, path: &str, reference: Option<&str>) -> String {
+        let path = path.trim_matches('/')
+            .split('/')
+            .map(encode_segment)
+            .collect::<Vec<String>>()
+            .join("/");
+        let url = format!("{}/{}/{}/contents/{}",
+                          REPOS_API_URL,
+                          self.owner,
+                          self.reponame,
+                          path);
+        match reference {
+            Some(reference) => append_query(&url, &[("ref", reference.to_owned())]),
+            None => url,
+        }
+    }
+
+    /// Returns the contents of a file, or the entries of a directory
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file or of the directory, in the repository
+    /// * `reference` - The branch, tag or commit SHA to read (the default branch if `None`)
+    ///
+    /// # Example
+    ///
+    /// `
+    /// match contents_client.get("Cargo.toml", Some("master")) {
+    ///     Ok(Contents::File(file)) => println!("{:?}", file.decode()),
+    ///     Ok(Contents::Directory(entries)) => println!("{} entries", entries.len()),
+    ///     Err(error) => println!("Oops, an error has occured: {:?}", error)
+    /// }
+    /// `
+    pub fn get(&self, path: &str, reference: Option<&str>) -> Result<Contents, Error> {
+        let url = self.url(path, reference);
+        let body = try!(self.github_client.process_request(Method::Get, &url, None));
+        // A directory is sent as an array of entries, anything else as a single object
+        if body.trim_left().starts_with('[') {
+            decode(body).map(Contents::Directory)
+        } else {
+            decode(body).map(Contents::File)
+        }
+    }
+
+    /// Returns the README of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The branch, tag or commit SHA to read (the default branch if `None`)
+    pub fn readme(&self, reference: Option<&str>) -> Result<Content, Error> {
+        let url = format!("{}/{}/{}/readme", REPOS_API_URL, self.owner, self.reponame);
+        let url = match reference {
+            Some(reference) => append_query(&url, &[("ref", reference.to_owned())]),
+            None => url,
+        };
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the raw content of a text file (up to 100 MB), without the base64 encoding.
+    /// Use `download` for a binary file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `reference` - The branch, tag or commit SHA to read (the default branch if `None`)
+    pub fn raw(&self, path: &str, reference: Option<&str>) -> Result<String, Error> {
+        let options = RequestOptions::new().accept(RAW_MEDIA_TYPE);
+        self.github_client
+            .request(Method::Get, &self.url(path, reference), None, &options)
+            .map(|(body, _)| body)
+    }
+
+    /// Downloads the raw content of a file (text or binary, up to 100 MB) into a writer, and
+    /// returns the number of bytes written
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `reference` - The branch, tag or commit SHA to read (the default branch if `None`)
+    /// * `writer` - The destination of the content
+    pub fn download<W: Write>(&self,
+                              path: &str,
+                              reference: Option<&str>,
+                              writer: &mut W)
+                              -> Result<u64, Error> {
+        let options = RequestOptions::new().accept(RAW_MEDIA_TYPE);
+        self.github_client.download(&self.url(path, reference), &options, writer)
+    }
+
+    /// Creates or updates a file, and returns the new content with the created commit
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `update` - The new content of the file, with the commit informations
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let update = FileUpdate::new("Bump the version", b"version = 2").sha("3d21ec53a331a6f037a91c368710b99387d012c1");
+    /// let result = contents_client.create_or_update("VERSION", &update);
+    /// `
+    pub fn create_or_update(&self, path: &str, update: &FileUpdate) -> Result<FileCommit, Error> {
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Put, &self.url(path, None), Some(body))
+    }
+
+    /// Deletes a file, and returns the created commit
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the repository
+    /// * `delete` - The blob SHA of the file to delete, with the commit informations
+    pub fn delete(&self, path: &str, delete: &FileDelete) -> Result<FileCommit, Error> {
+        let body = try!(encode(delete));
+        self.github_client.request_json(Method::Delete, &self.url(path, None), Some(body))
+    }
+}
+
+/// The type of an entry of a repository
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ContentType {
+    #[serde(rename = "file")]
+    File,
+    #[serde(rename = "dir")]
+    Dir,
+    #[serde(rename = "symlink")]
+    Symlink,
+    #[serde(rename = "submodule")]
+    Submodule,
+}
+
+/// Contains all necessary fields to define a file, a directory, a symlink or a submodule of a
+/// repository
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Content {
+    #[serde(rename = "type", skip_serializing_if="Option::is_none")]
+    pub content_type: Option<ContentType>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub path: Option<String>,
+    /// The blob SHA, needed to update or to delete the file
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub size: Option<usize>,
+    /// The encoding of the content (`base64`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub encoding: Option<String>,
+    /// The encoded content, only for a single file (use `decode` to get the bytes)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<String>,
+    /// The target of a symlink
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target: Option<String>,
+    /// The URL of the repository of a submodule
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub submodule_git_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub git_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub download_url: Option<String>,
+}
+
+impl Content {
+    /// Returns the decoded content of the file, or an empty vector if there is no content (like
+    /// for a directory entry, or a file bigger than 1 MB)
+    pub fn decode(&self) -> Result<Vec<u8>, Error> {
+        match self.content {
+            // Github wraps the base64 content every 60 characters
+            Some(ref content) => {
+                let content: String = content.split_whitespace().collect();
+                content.from_base64().map_err(Error::Base64)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// The contents of a path of a repository
+#[derive(Debug)]
+pub enum Contents {
+    /// A file (or a symlink, or a submodule)
+    File(Content),
+    /// The entries of a directory
+    Directory(Vec<Content>),
+}
+
+/// Fields to create or to update a file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileUpdate {
+    /// The commit message
+    pub message: String,
+    /// The new content of the file, encoded in base64
+    pub content: String,
+    /// The blob SHA of the file to replace (required to update a file)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    /// The branch to commit to (the default branch if `None`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub branch: Option<String>,
+    /// The author of the commit (the committer by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<CommitAuthor>,
+    /// The committer of the commit (the authenticated user by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<CommitAuthor>,
+}
+
+impl FileUpdate {
+    /// Returns the fields to create a file
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The commit message
+    /// * `content` - The content of the file (encoded in base64 by this function)
+    pub fn new(message: &str, content: &[u8]) -> Self {
+        FileUpdate {
+            message: message.to_owned(),
+            content: content.to_base64(STANDARD),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the blob SHA of the file to replace, to update an existing file
+    pub fn sha(mut self, sha: &str) -> Self {
+        self.sha = Some(sha.to_owned());
+        self
+    }
+
+    /// Sets the branch to commit to
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.branch = Some(branch.to_owned());
+        self
+    }
+}
+
+/// Fields to delete a file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileDelete {
+    /// The commit message
+    pub message: String,
+    /// The blob SHA of the file to delete
+    pub sha: String,
+    /// The branch to commit to (the default branch if `None`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub branch: Option<String>,
+    /// The author of the commit (the committer by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<CommitAuthor>,
+    /// The committer of the commit (the authenticated user by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<CommitAuthor>,
+}
+
+/// The result of the creation, the update or the deletion of a file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileCommit {
+    /// The new content of the file (`None` once deleted)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<Content>,
+    /// The created commit
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit: Option<GitCommit>,
+}
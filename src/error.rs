@@ -0,0 +1,195 @@
+///
+/// Module to compose with errors.
+/// Every call to the Github API returns a `Result` type that contains an `Error` structure on failure.
+///
+use hyper;
+use hyper::status::StatusCode;
+use rustc_serialize::base64::FromBase64Error;
+use serde_json;
+
+use std::error;
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+
+/// All the errors that can occur when communicating with the Github API
+#[derive(Debug)]
+pub enum Error {
+    /// An error from the transport layer (connection, TLS, I/O, ...)
+    Http(hyper::Error),
+    /// Github answered with an unsuccessful HTTP status code
+    Api {
+        /// The HTTP status code of the response
+        status: StatusCode,
+        /// The error message (and the detected errors) sent back by Github
+        error: GitHubErrorResult,
+    },
+    /// The response body cannot be converted to the expected structure
+    Deserialize {
+        /// The error from the JSON parser
+        error: serde_json::Error,
+        /// The raw body of the response
+        body: String,
+    },
+    /// The structure to send cannot be converted to JSON
+    Serialize(serde_json::Error),
+    /// Some base64 encoded content sent by Github (like the content of a file) cannot be decoded
+    Base64(FromBase64Error),
+    /// The body of a response cannot be read, or a downloaded content cannot be written to its
+    /// destination.
+    /// Unlike `Http`, the request may have been processed by Github.
+    Io(io::Error),
+    /// A set of file changes cannot be committed (like the deletion of a missing file)
+    Commit(String),
+    /// A given URL is not a valid HTTP/HTTPS URL
+    InvalidUrl(String),
+    /// The credentials cannot be used to authenticate requests
+    Auth(String),
+    /// The rate limit of the user has been reached
+    RateLimitExceeded {
+        /// The time (in UTC epoch seconds) at which the current rate limit window resets, if known
+        reset: Option<u64>,
+    },
+    /// A secondary rate limit has been reached, because requests have been sent too fast
+    SecondaryRateLimitExceeded {
+        /// The HTTP status code of the response (403 or 429)
+        status: StatusCode,
+        /// The delay (in seconds) to wait before sending a request again, if known
+        retry_after: Option<u64>,
+    },
+}
+
+impl Error {
+    /// Returns the HTTP status code sent back by Github, if the error comes from an unsuccessful response
+    pub fn status(&self) -> Option<StatusCode> {
+        match *self {
+            Error::Api { status, .. } => Some(status),
+            Error::RateLimitExceeded { .. } => Some(StatusCode::Forbidden),
+            Error::SecondaryRateLimitExceeded { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Http(ref error) => write!(f, "Error processing the request: {}", error),
+            Error::Api { ref status, ref error } => {
+                try!(write!(f, "Github returned an error (code status {}): {}", status, error.message));
+                for part in &error.errors {
+                    try!(write!(f, "\n- {}", part));
+                }
+                Ok(())
+            }
+            Error::Deserialize { ref error, ref body } => {
+                write!(f,
+                       "Error when converting the string request from Github to JSON: {}, for \
+                        response {}",
+                       error,
+                       body)
+            }
+            Error::Serialize(ref error) => {
+                write!(f, "Error converting the structure to send to JSON: {}", error)
+            }
+            Error::Base64(ref error) => write!(f, "Error decoding base64 content: {}", error),
+            Error::Io(ref error) => write!(f, "Error reading or writing a content: {}", error),
+            Error::Commit(ref message) => write!(f, "Cannot commit the changes: {}", message),
+            Error::InvalidUrl(ref url) => write!(f, "Invalid URL: {}", url),
+            Error::Auth(ref message) => write!(f, "Authentication error: {}", message),
+            Error::RateLimitExceeded { reset: Some(reset) } => {
+                write!(f, "The rate limit has been reached, and will be reset at {}", reset)
+            }
+            Error::RateLimitExceeded { reset: None } => write!(f, "The rate limit has been reached"),
+            Error::SecondaryRateLimitExceeded { retry_after: Some(retry_after), .. } => {
+                write!(f,
+                       "A secondary rate limit has been reached, retry after {} seconds",
+                       retry_after)
+            }
+            Error::SecondaryRateLimitExceeded { retry_after: None, .. } => {
+                write!(f, "A secondary rate limit has been reached")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Http(ref error) => error.description(),
+            Error::Api { ref error, .. } => &error.message,
+            Error::Deserialize { .. } => "cannot deserialize the response from Github",
+            Error::Serialize(..) => "cannot serialize the structure to send to Github",
+            Error::Base64(..) => "cannot decode base64 content",
+            Error::Io(ref error) => error.description(),
+            Error::Commit(ref message) => message,
+            Error::InvalidUrl(..) => "invalid URL",
+            Error::Auth(ref message) => message,
+            Error::RateLimitExceeded { .. } => "the rate limit has been reached",
+            Error::SecondaryRateLimitExceeded { .. } => "a secondary rate limit has been reached",
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        match *self {
+            Error::Http(ref error) => Some(error),
+            Error::Deserialize { ref error, .. } => Some(error),
+            Error::Serialize(ref error) => Some(error),
+            Error::Base64(ref error) => Some(error),
+            Error::Io(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(error: hyper::Error) -> Self {
+        Error::Http(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// A structure to get and process errors from Github
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubErrorResult {
+    /// The global error message
+    pub message: String,
+    /// All detected errors
+    #[serde(default)]
+    pub errors: Vec<GitHubErrorPart>,
+    /// A link to the documentation of the endpoint
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub documentation_url: Option<String>,
+}
+
+/// A structure to parse an error from Github
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubErrorPart {
+    /// The resource that contains the error
+    #[serde(default)]
+    pub resource: String,
+    /// The field that contains the error
+    #[serde(default)]
+    pub field: String,
+    /// The code that contains the error
+    #[serde(default)]
+    pub code: String,
+    /// The message to understand the error
+    #[serde(default)]
+    pub message: String,
+}
+
+impl fmt::Display for GitHubErrorPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}.{}: {}", self.resource, self.field, self.code));
+        if !self.message.is_empty() {
+            try!(write!(f, " ({})", self.message));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,554 @@
+///
+/// Module to compose with the git database of a repository.
+/// Github documentation available at https://developer.github.com/v3/git/.
+///
+use client::{GithubClient, encode, encode_segment};
+use commits::{CommitAuthor, GitCommit, Verification};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use rustc_serialize::base64::{FromBase64, STANDARD, ToBase64};
+
+/// Structure that represents a git data client.
+/// This structure is needed to communicate with the Github API, about the objects (blobs, trees,
+/// commits and tags) and the references of the git database of a repository.
+pub struct GitClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> GitClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the git database of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let git_client = GitClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        GitClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the git database of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/git", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Creates a blob, and returns its SHA
+    ///
+    /// # Argument
+    ///
+    /// * `blob` - The content of the blob
+    ///
+    /// # Example
+    ///
+    /// `let blob = git_client.create_blob(&NewBlob::utf8("Hello, world!"));`
+    pub fn create_blob(&self, blob: &NewBlob) -> Result<GitObject, Error> {
+        let url = format!("{}/blobs", self.url());
+        let body = try!(encode(blob));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns a blob
+    ///
+    /// # Argument
+    ///
+    /// * `sha` - The SHA of the blob
+    pub fn get_blob(&self, sha: &str) -> Result<Blob, Error> {
+        let url = format!("{}/blobs/{}", self.url(), sha);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns a tree
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - The SHA of the tree (or the name of a branch)
+    /// * `recursive` - Returns the entries of the subtrees too (the list may be truncated for a
+    /// big tree: see the `truncated` field)
+    pub fn get_tree(&self, sha: &str, recursive: bool) -> Result<Tree, Error> {
+        let url = if recursive {
+            format!("{}/trees/{}?recursive=1", self.url(), sha)
+        } else {
+            format!("{}/trees/{}", self.url(), sha)
+        };
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a tree, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `tree` - The entries of the tree, with the tree to update if any
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let tree = NewTree {
+    ///     base_tree: Some(String::from("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")),
+    ///     tree: vec![NewTreeEntry::content("README.md", "Hello"), NewTreeEntry::delete("TODO")],
+    /// };
+    /// let tree = git_client.create_tree(&tree);
+    /// `
+    pub fn create_tree(&self, tree: &NewTree) -> Result<Tree, Error> {
+        let url = format!("{}/trees", self.url());
+        let body = try!(encode(tree));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns a commit
+    ///
+    /// # Argument
+    ///
+    /// * `sha` - The SHA of the commit
+    pub fn get_commit(&self, sha: &str) -> Result<GitCommit, Error> {
+        let url = format!("{}/commits/{}", self.url(), sha);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a commit, and returns it.
+    /// No reference is updated: use `update_ref` to move a branch to the new commit.
+    ///
+    /// # Argument
+    ///
+    /// * `commit` - The commit to create
+    pub fn create_commit(&self, commit: &NewCommit) -> Result<GitCommit, Error> {
+        let url = format!("{}/commits", self.url());
+        let body = try!(encode(commit));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns the references of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace of the references to list, like `heads` or `tags` (all the
+    /// references if `None`)
+    /// * `options` - The pagination parameters
+    pub fn list_refs(&self, namespace: Option<&str>, options: &ListOptions) -> Pages<'a, Reference> {
+        let url = match namespace {
+            Some(namespace) => format!("{}/refs/{}", self.url(), ref_path(namespace)),
+            None => format!("{}/refs", self.url()),
+        };
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the references that start with a given name
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The beginning of the name of the references, like `heads/feature`
+    /// * `options` - The pagination parameters
+    pub fn matching_refs(&self, prefix: &str, options: &ListOptions) -> Pages<'a, Reference> {
+        let url = format!("{}/matching-refs/{}", self.url(), ref_path(prefix));
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a reference
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The name of the reference, like `heads/master` or `tags/v1.0.0`
+    pub fn get_ref(&self, reference: &str) -> Result<Reference, Error> {
+        let url = format!("{}/ref/{}", self.url(), ref_path(reference));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a reference, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The name of the reference, like `heads/feature` or `refs/heads/feature`
+    /// * `sha` - The SHA of the object the reference points to
+    pub fn create_ref(&self, reference: &str, sha: &str) -> Result<Reference, Error> {
+        let url = format!("{}/refs", self.url());
+        let body = try!(encode(&NewReference {
+            reference: format!("refs/{}", short_ref(reference)),
+            sha: sha.to_owned(),
+        }));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Moves a reference to another object, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The name of the reference, like `heads/master`
+    /// * `sha` - The SHA of the new object the reference points to
+    /// * `force` - Allows an update that is not a fast-forward
+    pub fn update_ref(&self, reference: &str, sha: &str, force: bool) -> Result<Reference, Error> {
+        let url = format!("{}/refs/{}", self.url(), ref_path(reference));
+        let body = try!(encode(&ReferenceUpdate {
+            sha: sha.to_owned(),
+            force: force,
+        }));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes a reference
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The name of the reference, like `heads/feature`
+    pub fn delete_ref(&self, reference: &str) -> Result<(), Error> {
+        let url = format!("{}/refs/{}", self.url(), ref_path(reference));
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+
+    /// Returns an annotated tag object
+    ///
+    /// # Argument
+    ///
+    /// * `sha` - The SHA of the tag object
+    pub fn get_tag(&self, sha: &str) -> Result<Tag, Error> {
+        let url = format!("{}/tags/{}", self.url(), sha);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates an annotated tag object, and returns it.
+    /// The tag is only visible once the `refs/tags/<tag>` reference has been created.
+    ///
+    /// # Argument
+    ///
+    /// * `tag` - The tag to create
+    pub fn create_tag(&self, tag: &NewTag) -> Result<Tag, Error> {
+        let url = format!("{}/tags", self.url());
+        let body = try!(encode(tag));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+}
+
+/// Returns the name of a reference without the `refs/` prefix, as expected in the URLs
+fn short_ref(reference: &str) -> &str {
+    let reference = reference.trim_matches('/');
+    if reference.starts_with("refs/") {
+        &reference["refs/".len()..]
+    } else {
+        reference
+    }
+}
+
+/// Returns the path of a reference in the URLs: its name without the `refs/` prefix, with each
+/// segment percent-encoded
+fn ref_path(reference: &str) -> String {
+    short_ref(reference)
+        .split('/')
+        .map(encode_segment)
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// The type of an object of the git database
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ObjectType {
+    #[serde(rename = "blob")]
+    Blob,
+    #[serde(rename = "tree")]
+    Tree,
+    #[serde(rename = "commit")]
+    Commit,
+    #[serde(rename = "tag")]
+    Tag,
+}
+
+/// A reference to an object of the git database
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitObject {
+    #[serde(rename = "type", skip_serializing_if="Option::is_none")]
+    pub object_type: Option<ObjectType>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// The encoding of the content of a blob
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BlobEncoding {
+    #[serde(rename = "utf-8")]
+    Utf8,
+    #[serde(rename = "base64")]
+    Base64,
+}
+
+/// Fields to create a blob
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewBlob {
+    pub content: String,
+    pub encoding: BlobEncoding,
+}
+
+impl NewBlob {
+    /// Returns a blob with a text content
+    pub fn utf8(content: &str) -> Self {
+        NewBlob {
+            content: content.to_owned(),
+            encoding: BlobEncoding::Utf8,
+        }
+    }
+
+    /// Returns a blob with a binary content (encoded in base64 by this function)
+    pub fn base64(content: &[u8]) -> Self {
+        NewBlob {
+            content: content.to_base64(STANDARD),
+            encoding: BlobEncoding::Base64,
+        }
+    }
+}
+
+/// Contains all necessary fields to define a blob
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Blob {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub size: Option<usize>,
+    /// The encoded content (use `decode` to get the bytes)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub encoding: Option<BlobEncoding>,
+}
+
+impl Blob {
+    /// Returns the decoded content of the blob
+    pub fn decode(&self) -> Result<Vec<u8>, Error> {
+        match (&self.content, self.encoding) {
+            (&Some(ref content), Some(BlobEncoding::Base64)) => {
+                let content: String = content.split_whitespace().collect();
+                content.from_base64().map_err(Error::Base64)
+            }
+            (&Some(ref content), _) => Ok(content.clone().into_bytes()),
+            (&None, _) => Ok(Vec::new()),
+        }
+    }
+}
+
+/// The file mode of an entry of a tree
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FileMode {
+    /// A file
+    #[serde(rename = "100644")]
+    File,
+    /// An executable file
+    #[serde(rename = "100755")]
+    Executable,
+    /// A subdirectory (a tree)
+    #[serde(rename = "040000")]
+    Directory,
+    /// A submodule (a commit)
+    #[serde(rename = "160000")]
+    Submodule,
+    /// A symbolic link
+    #[serde(rename = "120000")]
+    Symlink,
+}
+
+/// An entry of a tree
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TreeEntry {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub mode: Option<FileMode>,
+    #[serde(rename = "type", skip_serializing_if="Option::is_none")]
+    pub object_type: Option<ObjectType>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    /// The size of a blob
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub size: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Contains all necessary fields to define a tree
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tree {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub tree: Vec<TreeEntry>,
+    /// True if the entries of a recursive tree have been truncated
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub truncated: Option<bool>,
+}
+
+/// An entry of a tree to create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewTreeEntry {
+    pub path: String,
+    pub mode: FileMode,
+    #[serde(rename = "type")]
+    pub object_type: ObjectType,
+    /// The SHA of the object: `Some(None)` sends a `null` SHA, to delete the entry from the base
+    /// tree
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<Option<String>>,
+    /// The content of a new blob, instead of a SHA
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl NewTreeEntry {
+    /// Returns an entry that points to an existing blob
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the tree
+    /// * `mode` - The mode of the file
+    /// * `sha` - The SHA of the blob
+    pub fn blob(path: &str, mode: FileMode, sha: &str) -> Self {
+        NewTreeEntry {
+            path: path.to_owned(),
+            mode: mode,
+            object_type: ObjectType::Blob,
+            sha: Some(Some(sha.to_owned())),
+            content: None,
+        }
+    }
+
+    /// Returns a file entry with a text content (the blob is created with the tree)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, in the tree
+    /// * `content` - The content of the file
+    pub fn content(path: &str, content: &str) -> Self {
+        NewTreeEntry {
+            path: path.to_owned(),
+            mode: FileMode::File,
+            object_type: ObjectType::Blob,
+            sha: None,
+            content: Some(content.to_owned()),
+        }
+    }
+
+    /// Returns an entry that deletes a file from the base tree
+    ///
+    /// # Argument
+    ///
+    /// * `path` - The path of the file, in the base tree
+    pub fn delete(path: &str) -> Self {
+        NewTreeEntry {
+            path: path.to_owned(),
+            mode: FileMode::File,
+            object_type: ObjectType::Blob,
+            sha: Some(None),
+            content: None,
+        }
+    }
+}
+
+/// Fields to create a tree
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewTree {
+    /// The SHA of the tree to update: the entries of this tree that are not given are kept
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub base_tree: Option<String>,
+    pub tree: Vec<NewTreeEntry>,
+}
+
+/// Fields to create a commit
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewCommit {
+    pub message: String,
+    /// The SHA of the tree of the commit
+    pub tree: String,
+    /// The SHAs of the parents of the commit (none for a root commit, several for a merge commit)
+    pub parents: Vec<String>,
+    /// The author of the commit (the authenticated user by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<CommitAuthor>,
+    /// The committer of the commit (the author by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub committer: Option<CommitAuthor>,
+    /// The ASCII-armored signature of the commit
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Contains all necessary fields to define a reference
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Reference {
+    /// The full name of the reference, like `refs/heads/master`
+    #[serde(rename = "ref", skip_serializing_if="Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub object: Option<GitObject>,
+}
+
+/// Structure sent to create a reference
+#[derive(Serialize)]
+struct NewReference {
+    #[serde(rename = "ref")]
+    reference: String,
+    sha: String,
+}
+
+/// Structure sent to update a reference
+#[derive(Serialize)]
+struct ReferenceUpdate {
+    sha: String,
+    force: bool,
+}
+
+/// Fields to create an annotated tag object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewTag {
+    /// The name of the tag, like `v1.0.0`
+    pub tag: String,
+    pub message: String,
+    /// The SHA of the tagged object
+    pub object: String,
+    /// The type of the tagged object (usually a commit)
+    #[serde(rename = "type")]
+    pub object_type: ObjectType,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tagger: Option<CommitAuthor>,
+}
+
+/// Contains all necessary fields to define an annotated tag object
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tagger: Option<CommitAuthor>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub object: Option<GitObject>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub verification: Option<Verification>,
+}
@@ -0,0 +1,249 @@
+///
+/// Module to compose with issue comments.
+/// Github documentation available at https://developer.github.com/v3/issues/comments/.
+///
+use client::{GithubClient, RequestOptions, append_query, encode};
+use error::Error;
+use hyper::method::Method;
+use media::BodyFormat;
+use pagination::{Direction, ListOptions, Pages};
+use repo::REPOS_API_URL;
+use serde::Deserialize;
+use user::UserInfoStructure;
+
+/// Structure that represents an issue comments client.
+/// This structure is needed to communicate with the Github API, about the comments of the issues
+/// (and pull requests) of a repository.
+pub struct IssueCommentsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+    /// The format of the body of the comments, if not the default one
+    format: Option<BodyFormat>,
+}
+
+impl<'a> IssueCommentsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the comments of the
+    /// issues of a repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let comments_client = IssueCommentsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        IssueCommentsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+            format: None,
+        }
+    }
+
+    /// Sets the format of the body of the comments returned by this client
+    ///
+    /// # Argument
+    ///
+    /// * `format` - The format of the body (`body`, `body_text` and/or `body_html` fields)
+    ///
+    /// # Example
+    ///
+    /// `let comment = comments_client.format(BodyFormat::Html).get(42);`
+    pub fn format(mut self, format: BodyFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Returns the URL of the issues of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/issues", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the options of the requests, that correspond to the format of the body
+    fn request_options(&self) -> RequestOptions {
+        match self.format {
+            Some(format) => RequestOptions::new().accept(format.media_type()),
+            None => RequestOptions::new(),
+        }
+    }
+
+    /// Process a request, and returns the body of the response converted to a given structure
+    fn request<T: Deserialize>(&self,
+                               http_method: Method,
+                               url: &str,
+                               body: Option<String>)
+                               -> Result<T, Error> {
+        self.github_client.request_json_with_options(http_method, url, body, &self.request_options())
+    }
+
+    /// Returns the comments of an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `since` - Only the comments updated at or after this time, if any
+    /// (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    /// * `options` - The pagination parameters
+    pub fn list(&self,
+                number: usize,
+                since: Option<&str>,
+                options: &ListOptions)
+                -> Pages<'a, IssueComment> {
+        let mut params = options.to_params();
+        if let Some(since) = since {
+            params.push(("since", since.to_owned()));
+        }
+        let url = append_query(&format!("{}/{}/comments", self.url(), number), &params);
+        Pages::new(self.github_client, &url, self.request_options())
+    }
+
+    /// Returns the comments of all the issues of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list_for_repo(&self,
+                         filters: &IssueCommentListOptions,
+                         options: &ListOptions)
+                         -> Pages<'a, IssueComment> {
+        let mut params = filters.to_params();
+        params.extend(options.to_params());
+        let url = append_query(&format!("{}/comments", self.url()), &params);
+        Pages::new(self.github_client, &url, self.request_options())
+    }
+
+    /// Returns a comment
+    ///
+    /// # Argument
+    ///
+    /// * `comment_id` - The identifier of the comment
+    pub fn get(&self, comment_id: usize) -> Result<IssueComment, Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        self.request(Method::Get, &url, None)
+    }
+
+    /// Creates a comment on an issue, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `body` - The content of the comment, in Markdown
+    pub fn create(&self, number: usize, body: &str) -> Result<IssueComment, Error> {
+        let url = format!("{}/{}/comments", self.url(), number);
+        let body = try!(encode(&CommentBodyStructure { body: body.to_owned() }));
+        self.request(Method::Post, &url, Some(body))
+    }
+
+    /// Updates a comment, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The identifier of the comment
+    /// * `body` - The new content of the comment, in Markdown
+    pub fn update(&self, comment_id: usize, body: &str) -> Result<IssueComment, Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        let body = try!(encode(&CommentBodyStructure { body: body.to_owned() }));
+        self.request(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes a comment
+    ///
+    /// # Argument
+    ///
+    /// * `comment_id` - The identifier of the comment
+    pub fn delete(&self, comment_id: usize) -> Result<(), Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+}
+
+/// The field used to sort the comments of a repository
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueCommentSort {
+    Created,
+    Updated,
+}
+
+impl IssueCommentSort {
+    /// Returns the value of the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            IssueCommentSort::Created => "created",
+            IssueCommentSort::Updated => "updated",
+        }
+    }
+}
+
+/// Filters to list the comments of all the issues of a repository
+#[derive(Debug, Clone, Default)]
+pub struct IssueCommentListOptions {
+    /// The field to sort the comments
+    pub sort: Option<IssueCommentSort>,
+    /// The direction of the sort (ignored without sort)
+    pub direction: Option<Direction>,
+    /// Only the comments updated at or after this time (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    pub since: Option<String>,
+}
+
+impl IssueCommentListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(sort) = self.sort {
+            params.push(("sort", sort.as_str().to_owned()));
+        }
+        if let Some(direction) = self.direction {
+            params.push(("direction", direction.as_str().to_owned()));
+        }
+        if let Some(ref since) = self.since {
+            params.push(("since", since.to_owned()));
+        }
+        params
+    }
+}
+
+/// Structure sent to create or update a comment
+#[derive(Serialize)]
+pub struct CommentBodyStructure {
+    pub body: String,
+}
+
+/// Contains all necessary fields to define a comment of an issue
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IssueComment {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub issue_url: Option<String>,
+    /// The raw Markdown body (`BodyFormat::Raw` and `BodyFormat::Full`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    /// The text only body (`BodyFormat::Text` and `BodyFormat::Full`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body_text: Option<String>,
+    /// The HTML rendered body (`BodyFormat::Html` and `BodyFormat::Full`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_association: Option<String>,
+}
@@ -0,0 +1,432 @@
+///
+/// Module to compose with `issues`.
+/// Github documentation available at https://developer.github.com/v3/issues/.
+///
+use client::{GithubClient, append_query, encode};
+use error::Error;
+use hyper::method::Method;
+use pagination::{Direction, ListOptions, Pages};
+use repo::REPOS_API_URL;
+use user::UserInfoStructure;
+
+/// Structure that represents an issues client.
+/// This structure is needed to communicate with the Github API, about the issues of a repository.
+pub struct IssuesClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> IssuesClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the issues of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let issues_client = IssuesClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        IssuesClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the issues of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/issues", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the issues (and pull requests) of the repository that match some filters
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let filters = IssueListOptions { labels: vec![String::from("bug")], ..Default::default() };
+    /// for issue in issues_client.list(&filters, &ListOptions::default()) {
+    ///     println!("{:?}", issue);
+    /// }
+    /// `
+    pub fn list(&self, filters: &IssueListOptions, options: &ListOptions) -> Pages<'a, Issue> {
+        let url = append_query(&self.url(), &filters.to_params());
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns an issue of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the issue
+    pub fn get(&self, number: usize) -> Result<Issue, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates an issue in the repository, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `issue` - The issue to create
+    pub fn create(&self, issue: &NewIssue) -> Result<Issue, Error> {
+        let body = try!(encode(issue));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Updates an issue of the repository, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `update` - The fields of the issue to update
+    pub fn update(&self, number: usize, update: &IssueUpdate) -> Result<Issue, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Closes an issue of the repository, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the issue
+    pub fn close(&self, number: usize) -> Result<Issue, Error> {
+        self.update(number,
+                    &IssueUpdate { state: Some(IssueState::Closed), ..Default::default() })
+    }
+
+    /// Reopens an issue of the repository, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the issue
+    pub fn reopen(&self, number: usize) -> Result<Issue, Error> {
+        self.update(number,
+                    &IssueUpdate { state: Some(IssueState::Open), ..Default::default() })
+    }
+
+    /// Locks the conversation of an issue of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `reason` - The reason to lock the conversation, if any
+    pub fn lock(&self, number: usize, reason: Option<LockReason>) -> Result<(), Error> {
+        let url = format!("{}/{}/lock", self.url(), number);
+        let body = try!(encode(&LockStructure { lock_reason: reason }));
+        self.github_client.process_request(Method::Put, &url, Some(body)).map(|_| ())
+    }
+
+    /// Unlocks the conversation of an issue of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the issue
+    pub fn unlock(&self, number: usize) -> Result<(), Error> {
+        let url = format!("{}/{}/lock", self.url(), number);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+}
+
+/// The state of an issue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum IssueState {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "closed")]
+    Closed,
+}
+
+/// A filter on the state of the items of a list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StateFilter {
+    Open,
+    Closed,
+    All,
+}
+
+impl StateFilter {
+    /// Returns the value of the `state` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            StateFilter::Open => "open",
+            StateFilter::Closed => "closed",
+            StateFilter::All => "all",
+        }
+    }
+}
+
+/// The field used to sort issues
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueSort {
+    Created,
+    Updated,
+    Comments,
+}
+
+impl IssueSort {
+    /// Returns the value of the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            IssueSort::Created => "created",
+            IssueSort::Updated => "updated",
+            IssueSort::Comments => "comments",
+        }
+    }
+}
+
+/// Filters to list the issues of a repository
+#[derive(Debug, Clone, Default)]
+pub struct IssueListOptions {
+    /// The state of the issues (open by default)
+    pub state: Option<StateFilter>,
+    /// The names of labels that the issues must all have
+    pub labels: Vec<String>,
+    /// The login of the assignee, `none` for issues with no assignee or `*` for any assignee
+    pub assignee: Option<String>,
+    /// The login of the creator
+    pub creator: Option<String>,
+    /// The login of a user mentioned in the issues
+    pub mentioned: Option<String>,
+    /// The number of the milestone, `none` for issues with no milestone or `*` for any milestone
+    pub milestone: Option<String>,
+    /// Only the issues updated at or after this time (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    pub since: Option<String>,
+    /// The field to sort the issues
+    pub sort: Option<IssueSort>,
+    /// The direction of the sort
+    pub direction: Option<Direction>,
+}
+
+impl IssueListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(state) = self.state {
+            params.push(("state", state.as_str().to_owned()));
+        }
+        if !self.labels.is_empty() {
+            params.push(("labels", self.labels.join(",")));
+        }
+        if let Some(ref assignee) = self.assignee {
+            params.push(("assignee", assignee.to_owned()));
+        }
+        if let Some(ref creator) = self.creator {
+            params.push(("creator", creator.to_owned()));
+        }
+        if let Some(ref mentioned) = self.mentioned {
+            params.push(("mentioned", mentioned.to_owned()));
+        }
+        if let Some(ref milestone) = self.milestone {
+            params.push(("milestone", milestone.to_owned()));
+        }
+        if let Some(ref since) = self.since {
+            params.push(("since", since.to_owned()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort", sort.as_str().to_owned()));
+        }
+        if let Some(direction) = self.direction {
+            params.push(("direction", direction.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// The reason to lock the conversation of an issue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+}
+
+/// Structure sent to lock the conversation of an issue
+#[derive(Serialize)]
+struct LockStructure {
+    #[serde(skip_serializing_if="Option::is_none")]
+    lock_reason: Option<LockReason>,
+}
+
+/// Contains all necessary fields to define a label
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub default: Option<bool>,
+}
+
+/// Contains all necessary fields to define a milestone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub number: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub labels_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub creator: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub open_issues: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub closed_issues: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub closed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+/// Links to the pull request, if an issue is a pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuePullRequestLinks {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub diff_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub patch_url: Option<String>,
+}
+
+/// Contains all necessary fields to define an issue
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Issue {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub number: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repository_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub labels_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comments_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub events_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state_reason: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub assignee: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub assignees: Vec<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub milestone: Option<Milestone>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub active_lock_reason: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comments: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pull_request: Option<IssuePullRequestLinks>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub closed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub closed_by: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_association: Option<String>,
+}
+
+/// Fields to create an issue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewIssue {
+    pub title: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub milestone: Option<usize>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub labels: Vec<String>,
+}
+
+/// Fields that can be modified for a given issue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state_reason: Option<String>,
+    /// The number of the milestone: `Some(None)` sends a `null` milestone, to remove the issue
+    /// from its milestone
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub milestone: Option<Option<usize>>,
+    /// Replaces all the labels of the issue
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// Replaces all the assignees of the issue
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+}
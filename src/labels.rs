@@ -0,0 +1,216 @@
+///
+/// Module to compose with labels.
+/// Github documentation available at https://developer.github.com/v3/issues/labels/.
+///
+use client::{GithubClient, encode, encode_segment};
+use error::Error;
+use hyper::method::Method;
+use issues::Label;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+
+/// Structure that represents a labels client.
+/// This structure is needed to communicate with the Github API, about the labels of a repository
+/// and of its issues (and pull requests).
+pub struct LabelsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> LabelsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the labels of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let labels_client = LabelsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        LabelsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the URL of a label of the repository
+    fn label_url(&self, name: &str) -> String {
+        format!("{}/labels/{}", self.url(), encode_segment(name))
+    }
+
+    /// Returns the labels of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `options` - The pagination parameters
+    pub fn list(&self, options: &ListOptions) -> Pages<'a, Label> {
+        self.github_client.get_pages(&format!("{}/labels", self.url()), options)
+    }
+
+    /// Returns a label
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the label
+    pub fn get(&self, name: &str) -> Result<Label, Error> {
+        self.github_client.request_json(Method::Get, &self.label_url(name), None)
+    }
+
+    /// Creates a label, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `label` - The label to create
+    ///
+    /// # Example
+    ///
+    /// `let label = labels_client.create(&NewLabel::new("good first issue", "7057ff"));`
+    pub fn create(&self, label: &NewLabel) -> Result<Label, Error> {
+        let url = format!("{}/labels", self.url());
+        let body = try!(encode(label));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Updates a label (like renaming it), and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The current name of the label
+    /// * `update` - The fields of the label to update
+    pub fn update(&self, name: &str, update: &LabelUpdate) -> Result<Label, Error> {
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &self.label_url(name), Some(body))
+    }
+
+    /// Deletes a label (it is removed from all the issues)
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the label
+    pub fn delete(&self, name: &str) -> Result<(), Error> {
+        self.github_client.process_request(Method::Delete, &self.label_url(name), None).map(|_| ())
+    }
+
+    /// Returns the labels of an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `options` - The pagination parameters
+    pub fn list_for_issue(&self, number: usize, options: &ListOptions) -> Pages<'a, Label> {
+        let url = format!("{}/issues/{}/labels", self.url(), number);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Adds labels to an issue, and returns all the labels of the issue
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `names` - The names of the labels to add
+    pub fn add_to_issue(&self, number: usize, names: &[&str]) -> Result<Vec<Label>, Error> {
+        self.set_issue_labels(Method::Post, number, names)
+    }
+
+    /// Replaces all the labels of an issue, and returns them
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `names` - The names of the new labels of the issue
+    pub fn replace_for_issue(&self, number: usize, names: &[&str]) -> Result<Vec<Label>, Error> {
+        self.set_issue_labels(Method::Put, number, names)
+    }
+
+    /// Sends the names of some labels of an issue
+    fn set_issue_labels(&self,
+                        http_method: Method,
+                        number: usize,
+                        names: &[&str])
+                        -> Result<Vec<Label>, Error> {
+        let url = format!("{}/issues/{}/labels", self.url(), number);
+        let body = try!(encode(&IssueLabelsStructure {
+            labels: names.iter().map(|name| (*name).to_owned()).collect(),
+        }));
+        self.github_client.request_json(http_method, &url, Some(body))
+    }
+
+    /// Removes a label from an issue, and returns the remaining labels of the issue
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the issue
+    /// * `name` - The name of the label to remove
+    pub fn remove_from_issue(&self, number: usize, name: &str) -> Result<Vec<Label>, Error> {
+        let url = format!("{}/issues/{}/labels/{}", self.url(), number, encode_segment(name));
+        self.github_client.request_json(Method::Delete, &url, None)
+    }
+
+    /// Removes all the labels of an issue
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the issue
+    pub fn remove_all_from_issue(&self, number: usize) -> Result<(), Error> {
+        let url = format!("{}/issues/{}/labels", self.url(), number);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+}
+
+/// Fields to create a label
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewLabel {
+    pub name: String,
+    /// The hexadecimal color code, without the leading `#`
+    pub color: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NewLabel {
+    /// Returns the fields to create a label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label (emojis can be written like `:bug:`)
+    /// * `color` - The hexadecimal color code, like `f29513` (a leading `#` is removed)
+    pub fn new(name: &str, color: &str) -> Self {
+        NewLabel {
+            name: name.to_owned(),
+            color: color.trim_left_matches('#').to_owned(),
+            description: None,
+        }
+    }
+}
+
+/// Fields that can be modified for a given label
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub new_name: Option<String>,
+    /// The hexadecimal color code, without the leading `#`
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Structure sent to add or to replace the labels of an issue
+#[derive(Serialize)]
+struct IssueLabelsStructure {
+    labels: Vec<String>,
+}
@@ -1,29 +1,66 @@
 #![feature(custom_derive, custom_attribute, proc_macro)]
 
+#[cfg(feature = "async")]
+extern crate futures;
+#[cfg(feature = "async")]
+extern crate futures_cpupool;
 #[macro_use]
 extern crate hyper;
+extern crate openssl;
+extern crate rustc_serialize;
 extern crate serde;
 extern crate serde_json;
+extern crate url;
 #[macro_use]
 extern crate serde_derive;
 
+pub mod app;
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod auth;
+pub mod branches;
+pub mod cache;
+pub mod checks;
 pub mod client;
-pub mod user;
+pub mod commit_builder;
+pub mod commits;
+pub mod contents;
+pub mod error;
+pub mod git;
+pub mod issue_comments;
+pub mod issues;
+pub mod labels;
+pub mod media;
+pub mod milestones;
+pub mod pagination;
+pub mod pulls;
 pub mod rate_limits;
+pub mod releases;
 pub mod repo;
+pub mod retry;
+pub mod review_comments;
+pub mod reviews;
+pub mod statuses;
+pub mod user;
 
 // Custom headers
 header! { (XRateLimitLimit, "X-RateLimit-Limit") => [usize] }
 header! { (XRateLimitRemaining, "X-RateLimit-Remaining") => [usize] }
+header! { (XRateLimitReset, "X-RateLimit-Reset") => [u64] }
+header! { (XRateLimitUsed, "X-RateLimit-Used") => [usize] }
+header! { (XRateLimitResource, "X-RateLimit-Resource") => [String] }
+
+pub use error::Error;
+pub use retry::RetryAfter;
 
 /// A trait for structures that send a GET request
 pub trait GetterAPI {
     type GetType: std::fmt::Debug + serde::Serialize + serde::Deserialize;
-    fn get(&self) -> Result<Self::GetType, String>;
+    fn get(&self) -> Result<Self::GetType, Error>;
 }
 
 /// A trait for structures that send a PATCH request
 pub trait UpdaterAPI {
     type PatchType: std::fmt::Debug + serde::Serialize + serde::Deserialize;
-    fn patch(&self, &Self::PatchType) -> Result<String, String>;
+    fn patch(&self, &Self::PatchType) -> Result<String, Error>;
 }
\ No newline at end of file
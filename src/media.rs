@@ -0,0 +1,48 @@
+///
+/// Module to compose with media types.
+/// Github documentation available at https://developer.github.com/v3/media/.
+///
+
+/// The format of the body of comments, issues and pull requests (written in Markdown)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BodyFormat {
+    /// The raw Markdown body, in the `body` field (the default format)
+    Raw,
+    /// A text only representation of the body, in the `body_text` field
+    Text,
+    /// An HTML rendered representation of the body, in the `body_html` field
+    Html,
+    /// The raw, text and HTML representations, in the `body`, `body_text` and `body_html` fields
+    Full,
+}
+
+impl BodyFormat {
+    /// Returns the media type to accept, to get the body in this format
+    pub fn media_type(&self) -> &'static str {
+        match *self {
+            BodyFormat::Raw => "application/vnd.github.v3.raw+json",
+            BodyFormat::Text => "application/vnd.github.v3.text+json",
+            BodyFormat::Html => "application/vnd.github.v3.html+json",
+            BodyFormat::Full => "application/vnd.github.v3.full+json",
+        }
+    }
+}
+
+/// The format of a diff between commits, or of the changes of a pull request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffFormat {
+    /// The unified diff
+    Diff,
+    /// The changes as a series of patches, formatted for `git am`
+    Patch,
+}
+
+impl DiffFormat {
+    /// Returns the media type to accept, to get the raw text in this format
+    pub fn media_type(&self) -> &'static str {
+        match *self {
+            DiffFormat::Diff => "application/vnd.github.v3.diff",
+            DiffFormat::Patch => "application/vnd.github.v3.patch",
+        }
+    }
+}
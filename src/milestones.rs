@@ -0,0 +1,203 @@
+///
+/// Module to compose with milestones.
+/// Github documentation available at https://developer.github.com/v3/issues/milestones/.
+///
+use client::{GithubClient, append_query, encode};
+use error::Error;
+use hyper::method::Method;
+use issues::{IssueState, Milestone, StateFilter};
+use pagination::{Direction, ListOptions, Pages};
+use repo::REPOS_API_URL;
+
+/// Structure that represents a milestones client.
+/// This structure is needed to communicate with the Github API, about the milestones of a
+/// repository.
+pub struct MilestonesClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> MilestonesClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the milestones of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let milestones_client = MilestonesClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        MilestonesClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the milestones of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/milestones", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the milestones of the repository that match some filters
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list(&self,
+                filters: &MilestoneListOptions,
+                options: &ListOptions)
+                -> Pages<'a, Milestone> {
+        let url = append_query(&self.url(), &filters.to_params());
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a milestone
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the milestone
+    pub fn get(&self, number: usize) -> Result<Milestone, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a milestone, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `milestone` - The milestone to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut milestone = NewMilestone::new("v1.0");
+    /// milestone.due_on = Some(String::from("2017-06-30T23:59:59Z"));
+    /// let milestone = milestones_client.create(&milestone);
+    /// `
+    pub fn create(&self, milestone: &NewMilestone) -> Result<Milestone, Error> {
+        let body = try!(encode(milestone));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Updates a milestone (like closing it), and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the milestone
+    /// * `update` - The fields of the milestone to update
+    pub fn update(&self, number: usize, update: &MilestoneUpdate) -> Result<Milestone, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes a milestone
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the milestone
+    pub fn delete(&self, number: usize) -> Result<(), Error> {
+        let url = format!("{}/{}", self.url(), number);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+}
+
+/// The field used to sort milestones
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MilestoneSort {
+    /// Sort by due date (the default)
+    DueOn,
+    /// Sort by the ratio of closed issues
+    Completeness,
+}
+
+impl MilestoneSort {
+    /// Returns the value of the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            MilestoneSort::DueOn => "due_on",
+            MilestoneSort::Completeness => "completeness",
+        }
+    }
+}
+
+/// Filters to list the milestones of a repository
+#[derive(Debug, Clone, Default)]
+pub struct MilestoneListOptions {
+    /// The state of the milestones (open by default)
+    pub state: Option<StateFilter>,
+    /// The field to sort the milestones
+    pub sort: Option<MilestoneSort>,
+    /// The direction of the sort
+    pub direction: Option<Direction>,
+}
+
+impl MilestoneListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(state) = self.state {
+            params.push(("state", state.as_str().to_owned()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort", sort.as_str().to_owned()));
+        }
+        if let Some(direction) = self.direction {
+            params.push(("direction", direction.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// Fields to create a milestone
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewMilestone {
+    pub title: String,
+    /// The state of the milestone (open by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    /// The due date (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+impl NewMilestone {
+    /// Returns the fields to create an open milestone
+    ///
+    /// # Argument
+    ///
+    /// * `title` - The title of the milestone
+    pub fn new(title: &str) -> Self {
+        NewMilestone {
+            title: title.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Fields that can be modified for a given milestone
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MilestoneUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    /// The due date (ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`): `Some(None)` sends a `null` due
+    /// date, to remove it
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_on: Option<Option<String>>,
+}
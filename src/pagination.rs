@@ -0,0 +1,236 @@
+///
+/// Module to compose with paginated lists.
+/// Github documentation available at https://developer.github.com/v3/#pagination.
+///
+use client::{GithubClient, RequestOptions};
+use error::Error;
+use hyper::header::Headers;
+use hyper::method::Method;
+use serde::Deserialize;
+use serde_json;
+
+use std::vec;
+
+/// Name of the header that contains the links to other pages
+const LINK_HEADER: &'static str = "Link";
+
+/// Parameters to request a given page of a list
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// The number of items per page (up to 100 for most lists)
+    pub per_page: Option<usize>,
+    /// The number of the page to request, starting at 1
+    pub page: Option<usize>,
+}
+
+impl ListOptions {
+    /// Returns the query parameters that correspond to the options
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(per_page) = self.per_page {
+            params.push(("per_page", per_page.to_string()));
+        }
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        params
+    }
+}
+
+/// The direction to sort the items of a list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+impl Direction {
+    /// Returns the value of the `direction` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Direction::Asc => "asc",
+            Direction::Desc => "desc",
+        }
+    }
+}
+
+/// The links to other pages of a list, given by the Link header of a response
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Links {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+    pub first: Option<String>,
+    pub last: Option<String>,
+}
+
+/// Returns the links contained in the value of a Link header, like
+/// `<https://api.github.com/user/repos?page=3>; rel="next", <https://api.github.com/user/repos?page=50>; rel="last"`
+///
+/// # Argument
+///
+/// * `value` - A string slice that holds the value of a Link header
+pub fn parse_link_header(value: &str) -> Links {
+    let mut links = Links::default();
+    for link in value.split(',') {
+        let link = link.trim();
+        let (start, end) = match (link.find('<'), link.find('>')) {
+            (Some(start), Some(end)) if start < end => (start, end),
+            _ => continue,
+        };
+        let url = &link[start + 1..end];
+        for param in link[end + 1..].split(';') {
+            let mut param = param.splitn(2, '=');
+            if param.next().map(|key| key.trim()) != Some("rel") {
+                continue;
+            }
+            let rels = param.next().unwrap_or("").trim().trim_matches('"');
+            for rel in rels.split_whitespace() {
+                let field = match rel {
+                    "next" => &mut links.next,
+                    "prev" => &mut links.prev,
+                    "first" => &mut links.first,
+                    "last" => &mut links.last,
+                    _ => continue,
+                };
+                *field = Some(url.to_owned());
+            }
+        }
+    }
+    links
+}
+
+/// A page of a list of items
+#[derive(Debug)]
+pub struct Page<T> {
+    /// The items of the page
+    pub items: Vec<T>,
+    /// The links to the other pages of the list
+    pub links: Links,
+}
+
+impl<T: Deserialize> Page<T> {
+    /// Returns a page from the body and the headers of a response
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The body of the response, that contains a JSON array of items
+    /// * `headers` - The headers of the response
+    pub fn from_response(body: String, headers: &Headers) -> Result<Self, Error> {
+        let links = match headers.get_raw(LINK_HEADER) {
+            Some(values) => {
+                let values: Vec<String> = values.iter()
+                    .map(|value| String::from_utf8_lossy(value).into_owned())
+                    .collect();
+                parse_link_header(&values.join(","))
+            }
+            None => Links::default(),
+        };
+        match serde_json::from_str(&body) {
+            Ok(items) => {
+                Ok(Page {
+                    items: items,
+                    links: links,
+                })
+            }
+            Err(error) => {
+                Err(Error::Deserialize {
+                    error: error,
+                    body: body,
+                })
+            }
+        }
+    }
+}
+
+/// An iterator over all the items of a list, which requests the next pages lazily.
+/// The list can also be requested page by page, using `next_page`.
+/// The iteration stops after the first error.
+pub struct Pages<'a, T> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// The URL of the next page to request, if any
+    next_url: Option<String>,
+    /// The options of the requests
+    request_options: RequestOptions,
+    /// The links of the current page
+    links: Links,
+    /// The remaining items of the current page
+    items: vec::IntoIter<T>,
+}
+
+impl<'a, T: Deserialize> Pages<'a, T> {
+    /// Returns an iterator over the items of a list, starting at a given URL
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `url` - A string slice that holds the URL of the first page to request
+    /// * `request_options` - The options of the requests (like the media type to accept)
+    pub fn new(github_client: &'a GithubClient,
+               url: &str,
+               request_options: RequestOptions)
+               -> Self {
+        Pages {
+            github_client: github_client,
+            next_url: Some(url.to_owned()),
+            request_options: request_options,
+            links: Links::default(),
+            items: Vec::new().into_iter(),
+        }
+    }
+
+    /// Returns the next page of the list, or the remaining items of the current page if some
+    /// items have already been iterated
+    pub fn next_page(&mut self) -> Option<Result<Page<T>, Error>> {
+        let items: Vec<T> = self.items.by_ref().collect();
+        if !items.is_empty() {
+            return Some(Ok(Page {
+                items: items,
+                links: self.links.clone(),
+            }));
+        }
+        let url = match self.next_url.take() {
+            Some(url) => url,
+            None => return None,
+        };
+        match self.request_page(&url) {
+            Ok(page) => {
+                self.next_url = page.links.next.clone();
+                self.links = page.links.clone();
+                Some(Ok(page))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+
+    /// Returns the page at a given URL
+    fn request_page(&self, url: &str) -> Result<Page<T>, Error> {
+        let (body, headers) =
+            try!(self.github_client.request(Method::Get, url, None, &self.request_options));
+        Page::from_response(body, &headers)
+    }
+}
+
+impl<'a, T: Deserialize> Iterator for Pages<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            let url = match self.next_url.take() {
+                Some(url) => url,
+                None => return None,
+            };
+            match self.request_page(&url) {
+                Ok(page) => {
+                    self.next_url = page.links.next.clone();
+                    self.links = page.links;
+                    self.items = page.items.into_iter();
+                }
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
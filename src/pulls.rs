@@ -0,0 +1,498 @@
+///
+/// Module to compose with `pulls`.
+/// Github documentation available at https://developer.github.com/v3/pulls/.
+///
+use client::{GithubClient, RequestOptions, append_query, encode};
+use commits::{Commit, DiffFile};
+use error::Error;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use issues::{IssueState, Label, Milestone, StateFilter};
+use media::DiffFormat;
+use pagination::{Direction, ListOptions, Pages};
+use repo::{REPOS_API_URL, RepoInfoStructure};
+use review_comments::ReviewCommentsClient;
+use reviews::{RequestedReviewersClient, ReviewsClient};
+use user::UserInfoStructure;
+
+/// Structure that represents a pull requests client.
+/// This structure is needed to communicate with the Github API, about the pull requests of a
+/// repository.
+pub struct PullRequestsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> PullRequestsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the pull requests of
+    /// a repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let pulls_client = PullRequestsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        PullRequestsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the pull requests of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/pulls", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the pull requests of the repository that match some filters
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list(&self,
+                filters: &PullRequestListOptions,
+                options: &ListOptions)
+                -> Pages<'a, PullRequest> {
+        let url = append_query(&self.url(), &filters.to_params());
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a pull request of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the pull request
+    pub fn get(&self, number: usize) -> Result<PullRequest, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a pull request in the repository, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `pull_request` - The pull request to create
+    ///
+    /// # Example
+    ///
+    /// `let pull_request = pulls_client.create(&NewPullRequest::from_branch("Release 1.0", "release-1.0", "master"));`
+    pub fn create(&self, pull_request: &NewPullRequest) -> Result<PullRequest, Error> {
+        let body = try!(encode(pull_request));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Updates a pull request of the repository, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `update` - The fields of the pull request to update
+    pub fn update(&self, number: usize, update: &PullRequestUpdate) -> Result<PullRequest, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Returns true if a pull request has been merged
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the pull request
+    pub fn is_merged(&self, number: usize) -> Result<bool, Error> {
+        let url = format!("{}/{}/merge", self.url(), number);
+        match self.github_client.process_request(Method::Get, &url, None) {
+            Ok(_) => Ok(true),
+            Err(Error::Api { status: StatusCode::NotFound, .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Merges a pull request, and returns the result of the merge
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `merge` - The parameters of the merge (method, commit message, expected head SHA)
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let merge = MergeRequest { merge_method: Some(MergeMethod::Squash), ..Default::default() };
+    /// let result = pulls_client.merge(42, &merge);
+    /// `
+    pub fn merge(&self, number: usize, merge: &MergeRequest) -> Result<MergeResult, Error> {
+        let url = format!("{}/{}/merge", self.url(), number);
+        let body = try!(encode(merge));
+        self.github_client.request_json(Method::Put, &url, Some(body))
+    }
+
+    /// Updates the head branch of a pull request with the latest changes of the base branch
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `expected_head_sha` - The expected SHA of the head of the pull request, if any: the
+    /// update fails if it does not match
+    pub fn update_branch(&self,
+                         number: usize,
+                         expected_head_sha: Option<&str>)
+                         -> Result<UpdateBranchResult, Error> {
+        let url = format!("{}/{}/update-branch", self.url(), number);
+        let body = try!(encode(&UpdateBranchStructure {
+            expected_head_sha: expected_head_sha.map(|sha| sha.to_owned()),
+        }));
+        self.github_client.request_json(Method::Put, &url, Some(body))
+    }
+
+    /// Returns the files changed by a pull request (at most 3000 files)
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `options` - The pagination parameters
+    pub fn files(&self, number: usize, options: &ListOptions) -> Pages<'a, DiffFile> {
+        let url = format!("{}/{}/files", self.url(), number);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the commits of a pull request (at most 250 commits)
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `options` - The pagination parameters
+    pub fn commits(&self, number: usize, options: &ListOptions) -> Pages<'a, Commit> {
+        let url = format!("{}/{}/commits", self.url(), number);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the changes of a pull request as raw text, in a diff or patch format
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `format` - The format of the changes
+    ///
+    /// # Example
+    ///
+    /// `let diff = pulls_client.diff(42, DiffFormat::Diff);`
+    pub fn diff(&self, number: usize, format: DiffFormat) -> Result<String, Error> {
+        let url = format!("{}/{}", self.url(), number);
+        let options = RequestOptions::new().accept(format.media_type());
+        self.github_client.request(Method::Get, &url, None, &options).map(|(body, _)| body)
+    }
+
+    /// Returns a client for the reviews of a pull request
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the pull request
+    pub fn reviews(&self, number: usize) -> ReviewsClient<'a> {
+        ReviewsClient::new(self.github_client, &self.owner, &self.reponame, number)
+    }
+
+    /// Returns a client for the review comments of the pull requests
+    pub fn review_comments(&self) -> ReviewCommentsClient<'a> {
+        ReviewCommentsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client for the requested reviewers of a pull request
+    ///
+    /// # Argument
+    ///
+    /// * `number` - The number of the pull request
+    pub fn requested_reviewers(&self, number: usize) -> RequestedReviewersClient<'a> {
+        RequestedReviewersClient::new(self.github_client, &self.owner, &self.reponame, number)
+    }
+}
+
+/// The field used to sort pull requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PullRequestSort {
+    Created,
+    Updated,
+    /// Sort by the number of comments
+    Popularity,
+    /// Sort by age, filtering out pull requests less than a month old
+    LongRunning,
+}
+
+impl PullRequestSort {
+    /// Returns the value of the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            PullRequestSort::Created => "created",
+            PullRequestSort::Updated => "updated",
+            PullRequestSort::Popularity => "popularity",
+            PullRequestSort::LongRunning => "long-running",
+        }
+    }
+}
+
+/// Filters to list the pull requests of a repository
+#[derive(Debug, Clone, Default)]
+pub struct PullRequestListOptions {
+    /// The state of the pull requests (open by default)
+    pub state: Option<StateFilter>,
+    /// The head branch, as `user:ref-name` or `organization:ref-name`
+    pub head: Option<String>,
+    /// The base branch
+    pub base: Option<String>,
+    /// The field to sort the pull requests
+    pub sort: Option<PullRequestSort>,
+    /// The direction of the sort
+    pub direction: Option<Direction>,
+}
+
+impl PullRequestListOptions {
+    /// Returns the query parameters that correspond to the filters
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(state) = self.state {
+            params.push(("state", state.as_str().to_owned()));
+        }
+        if let Some(ref head) = self.head {
+            params.push(("head", head.to_owned()));
+        }
+        if let Some(ref base) = self.base {
+            params.push(("base", base.to_owned()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort", sort.as_str().to_owned()));
+        }
+        if let Some(direction) = self.direction {
+            params.push(("direction", direction.as_str().to_owned()));
+        }
+        params
+    }
+}
+
+/// A branch (the head or the base) of a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullRequestRef {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub label: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if="Option::is_none")]
+    pub ref_name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repo: Option<RepoInfoStructure>,
+}
+
+/// Contains all necessary fields to define a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullRequest {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub number: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub diff_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub patch_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub issue_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub milestone: Option<Milestone>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub assignee: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub assignees: Vec<UserInfoStructure>,
+    #[serde(default)]
+    pub requested_reviewers: Vec<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub head: Option<PullRequestRef>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub base: Option<PullRequestRef>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merged: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub mergeable: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub mergeable_state: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merged_by: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merge_commit_sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comments: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub review_comments: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commits: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub additions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deletions: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub changed_files: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub closed_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merged_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_association: Option<String>,
+}
+
+/// Fields to create a pull request, from a branch or from an existing issue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewPullRequest {
+    /// The title of the pull request (required, unless the pull request is created from an issue)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    /// The branch that contains the changes, as `ref-name` or `user:ref-name` for a fork
+    pub head: String,
+    /// The branch to merge the changes into
+    pub base: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+    /// The number of the issue to convert into a pull request (the title and the body of the issue
+    /// are used)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub issue: Option<usize>,
+}
+
+impl NewPullRequest {
+    /// Returns the fields to create a pull request from a branch
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the pull request
+    /// * `head` - The branch that contains the changes
+    /// * `base` - The branch to merge the changes into
+    pub fn from_branch(title: &str, head: &str, base: &str) -> Self {
+        NewPullRequest {
+            title: Some(title.to_owned()),
+            head: head.to_owned(),
+            base: base.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the fields to convert an existing issue into a pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `issue` - The number of the issue
+    /// * `head` - The branch that contains the changes
+    /// * `base` - The branch to merge the changes into
+    pub fn from_issue(issue: usize, head: &str, base: &str) -> Self {
+        NewPullRequest {
+            issue: Some(issue),
+            head: head.to_owned(),
+            base: base.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Fields that can be modified for a given pull request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PullRequestUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub base: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+}
+
+/// The method to merge a pull request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MergeMethod {
+    #[serde(rename = "merge")]
+    Merge,
+    #[serde(rename = "squash")]
+    Squash,
+    #[serde(rename = "rebase")]
+    Rebase,
+}
+
+/// Parameters to merge a pull request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeRequest {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_title: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_message: Option<String>,
+    /// The SHA that the head of the pull request must match to allow the merge
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    /// The method to merge the pull request (a merge commit by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merge_method: Option<MergeMethod>,
+}
+
+/// The result of the merge of a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MergeResult {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub merged: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Structure sent to update the head branch of a pull request
+#[derive(Serialize)]
+struct UpdateBranchStructure {
+    #[serde(skip_serializing_if="Option::is_none")]
+    expected_head_sha: Option<String>,
+}
+
+/// The result of the update of the head branch of a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateBranchResult {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+}
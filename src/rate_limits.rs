@@ -1,12 +1,21 @@
 use client::GithubClient;
+use Error;
 use GetterAPI;
+use hyper::header::Headers;
 use hyper::method::Method;
 use serde_json;
+use {XRateLimitLimit, XRateLimitRemaining, XRateLimitReset, XRateLimitResource, XRateLimitUsed};
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Static string that corresponds to the rate URL
 const RATELIMITS_API_URL: &'static str = "rate_limit";
 
-/// Structure to communicate with the Github API
+/// Structure to communicate with the Github API.
+/// The rate limit given by the headers of the last response is also available, without any
+/// request, using `GithubClient::rate`.
 pub struct RateLimits<'a> {
     github_client: &'a GithubClient,
 }
@@ -21,16 +30,16 @@ impl<'a> RateLimits<'a> {
 /// Get informations about rate limits, using GetterAPI trait
 impl<'a> GetterAPI for RateLimits<'a> {
     type GetType = Limits;
-    fn get(&self) -> Result<Limits, String> {
+    fn get(&self) -> Result<Limits, Error> {
         match self.github_client.process_request(Method::Get, RATELIMITS_API_URL, None) {
             Ok(response) => {
                 match serde_json::from_str(&response) {
                     Ok(rate_limits) => Ok(rate_limits),
                     Err(error) => {
-                        Err(format!("Error when converting the string request from Github to \
-                                     JSON: {}, for response {}",
-                                    error,
-                                    response))
+                        Err(Error::Deserialize {
+                            error: error,
+                            body: response,
+                        })
                     }
                 }
             }
@@ -40,14 +49,109 @@ impl<'a> GetterAPI for RateLimits<'a> {
 }
 
 /// Atomistic structure for core, graphql, search and rate fields
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Rate {
     #[serde(skip_serializing_if="Option::is_none")]
-    limit: Option<usize>,
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub remaining: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    remaining: Option<usize>,
+    pub reset: Option<u64>,
     #[serde(skip_serializing_if="Option::is_none")]
-    reset: Option<usize>,
+    pub used: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub resource: Option<String>,
+}
+
+impl Rate {
+    /// Returns the rate limit given by the X-RateLimit-* headers of a response, if any
+    ///
+    /// # Argument
+    ///
+    /// * `headers` - The headers of a response from Github
+    pub fn from_headers(headers: &Headers) -> Option<Rate> {
+        let rate = Rate {
+            limit: headers.get::<XRateLimitLimit>().map(|value| value.0),
+            remaining: headers.get::<XRateLimitRemaining>().map(|value| value.0),
+            reset: headers.get::<XRateLimitReset>().map(|value| value.0),
+            used: headers.get::<XRateLimitUsed>().map(|value| value.0),
+            resource: headers.get::<XRateLimitResource>().map(|value| value.0.to_owned()),
+        };
+        if rate.limit.is_none() && rate.remaining.is_none() {
+            None
+        } else {
+            Some(rate)
+        }
+    }
+}
+
+/// What to do when the rate limit has been reached
+#[derive(Clone)]
+pub enum RateLimitPolicy {
+    /// Returns an `Error::RateLimitExceeded` error
+    Error,
+    /// Sleeps until the rate limit is reset (up to an hour for the primary rate limit), and sends
+    /// the request again.
+    /// The wait does not count against the retry policy of the client.
+    Wait {
+        /// The maximum time to wait for the rate limit to be reset, for a single request
+        /// (unbounded if `None`): an `Error::RateLimitExceeded` error is returned beyond it
+        max_wait: Option<Duration>,
+    },
+    /// Calls a function with the current rate limit: if the function returns `true`, the request
+    /// is sent again once the rate limit is reset (like with `Wait`), else an
+    /// `Error::RateLimitExceeded` error is returned
+    Callback(Arc<Fn(&Rate) -> bool + Send + Sync>),
+}
+
+impl RateLimitPolicy {
+    /// Returns the delay to wait for the rate limit to be reset before sending a request again, or
+    /// None if the `Error::RateLimitExceeded` error must be returned
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The rate limit given by the last response
+    /// * `reset` - The time (in UTC epoch seconds) at which the rate limit resets, if known
+    /// * `waited` - The time already waited for the rate limit to be reset, for the request
+    /// * `now` - The current time, in UTC epoch seconds
+    pub fn delay(&self,
+                 rate: &Rate,
+                 reset: Option<u64>,
+                 waited: Duration,
+                 now: u64)
+                 -> Option<Duration> {
+        let reset = match reset {
+            Some(reset) => reset,
+            None => return None,
+        };
+        // Wait one more second, to let some slack with the clock of Github
+        let delay = Duration::from_secs(reset.saturating_sub(now) + 1);
+        match *self {
+            RateLimitPolicy::Error => None,
+            RateLimitPolicy::Wait { max_wait: Some(max_wait) } if waited + delay > max_wait => None,
+            RateLimitPolicy::Wait { .. } => Some(delay),
+            RateLimitPolicy::Callback(ref callback) if (**callback)(rate) => Some(delay),
+            RateLimitPolicy::Callback(_) => None,
+        }
+    }
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        RateLimitPolicy::Error
+    }
+}
+
+impl fmt::Debug for RateLimitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RateLimitPolicy::Error => write!(f, "Error"),
+            RateLimitPolicy::Wait { max_wait } => {
+                f.debug_struct("Wait").field("max_wait", &max_wait).finish()
+            }
+            RateLimitPolicy::Callback(_) => write!(f, "Callback"),
+        }
+    }
 }
 
 /// Structure that contains big fields about rate limits
@@ -0,0 +1,386 @@
+///
+/// Module to compose with releases, and their assets.
+/// Github documentation available at https://developer.github.com/v3/repos/releases/.
+///
+use client::{GithubClient, RequestOptions, append_query, decode, encode, encode_segment};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use std::io::{Read, Write};
+use user::UserInfoStructure;
+
+/// The media type to accept, to download the binary content of an asset
+const OCTET_STREAM_MEDIA_TYPE: &'static str = "application/octet-stream";
+
+/// Structure that represents a releases client.
+/// This structure is needed to communicate with the Github API, about the releases of a
+/// repository and their assets.
+pub struct ReleasesClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> ReleasesClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the releases of a
+    /// repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let releases_client = ReleasesClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        ReleasesClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the releases of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/releases", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the releases of the repository (including the drafts, for the users with push
+    /// access), the most recent first
+    ///
+    /// # Argument
+    ///
+    /// * `options` - The pagination parameters
+    pub fn list(&self, options: &ListOptions) -> Pages<'a, Release> {
+        self.github_client.get_pages(&self.url(), options)
+    }
+
+    /// Returns a release
+    ///
+    /// # Argument
+    ///
+    /// * `release_id` - The identifier of the release
+    pub fn get(&self, release_id: usize) -> Result<Release, Error> {
+        let url = format!("{}/{}", self.url(), release_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the published release of a tag
+    ///
+    /// # Argument
+    ///
+    /// * `tag` - The name of the tag, like `v1.0.0`
+    pub fn get_by_tag(&self, tag: &str) -> Result<Release, Error> {
+        let url = format!("{}/tags/{}", self.url(), encode_segment(tag));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Returns the latest published release (drafts and prereleases are ignored)
+    pub fn latest(&self) -> Result<Release, Error> {
+        let url = format!("{}/latest", self.url());
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a release, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `release` - The release to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let release = NewRelease { draft: Some(true), generate_release_notes: Some(true), ..NewRelease::new("v1.0.0") };
+    /// let release = releases_client.create(&release);
+    /// `
+    pub fn create(&self, release: &NewRelease) -> Result<Release, Error> {
+        let body = try!(encode(release));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Updates a release (like publishing a draft), and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `release_id` - The identifier of the release
+    /// * `update` - The fields of the release to update
+    pub fn update(&self, release_id: usize, update: &ReleaseUpdate) -> Result<Release, Error> {
+        let url = format!("{}/{}", self.url(), release_id);
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes a release (the tag is kept)
+    ///
+    /// # Argument
+    ///
+    /// * `release_id` - The identifier of the release
+    pub fn delete(&self, release_id: usize) -> Result<(), Error> {
+        let url = format!("{}/{}", self.url(), release_id);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+
+    /// Returns the assets of a release
+    ///
+    /// # Arguments
+    ///
+    /// * `release_id` - The identifier of the release
+    /// * `options` - The pagination parameters
+    pub fn list_assets(&self, release_id: usize, options: &ListOptions) -> Pages<'a, ReleaseAsset> {
+        let url = format!("{}/{}/assets", self.url(), release_id);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns an asset
+    ///
+    /// # Argument
+    ///
+    /// * `asset_id` - The identifier of the asset
+    pub fn get_asset(&self, asset_id: usize) -> Result<ReleaseAsset, Error> {
+        let url = format!("{}/assets/{}", self.url(), asset_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Uploads an asset to a release, streaming its content, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `release_id` - The identifier of the release
+    /// * `asset` - The name, the label and the media type of the asset
+    /// * `reader` - The content of the asset
+    /// * `size` - The size of the content, in bytes
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut file = File::open("target/release/app.tar.gz").unwrap();
+    /// let size = file.metadata().unwrap().len();
+    /// let asset = NewAsset::new("app-linux-x86_64.tar.gz", "application/gzip");
+    /// let asset = releases_client.upload_asset(42, &asset, &mut file, size);
+    /// `
+    pub fn upload_asset(&self,
+                        release_id: usize,
+                        asset: &NewAsset,
+                        reader: &mut Read,
+                        size: u64)
+                        -> Result<ReleaseAsset, Error> {
+        let mut params = vec![("name", asset.name.clone())];
+        if let Some(ref label) = asset.label {
+            params.push(("label", label.clone()));
+        }
+        let url = append_query(&format!("{}/{}/assets", self.url(), release_id), &params);
+        let body = try!(self.github_client.upload(&url, &asset.content_type, reader, size));
+        decode(body)
+    }
+
+    /// Updates the name or the label of an asset, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_id` - The identifier of the asset
+    /// * `update` - The fields of the asset to update
+    pub fn update_asset(&self,
+                        asset_id: usize,
+                        update: &AssetUpdate)
+                        -> Result<ReleaseAsset, Error> {
+        let url = format!("{}/assets/{}", self.url(), asset_id);
+        let body = try!(encode(update));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes an asset
+    ///
+    /// # Argument
+    ///
+    /// * `asset_id` - The identifier of the asset
+    pub fn delete_asset(&self, asset_id: usize) -> Result<(), Error> {
+        let url = format!("{}/assets/{}", self.url(), asset_id);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+
+    /// Downloads the binary content of an asset into a writer, and returns the number of bytes
+    /// written
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_id` - The identifier of the asset
+    /// * `writer` - The destination of the content
+    pub fn download_asset<W: Write>(&self, asset_id: usize, writer: &mut W) -> Result<u64, Error> {
+        let url = format!("{}/assets/{}", self.url(), asset_id);
+        let options = RequestOptions::new().accept(OCTET_STREAM_MEDIA_TYPE);
+        self.github_client.download(&url, &options, writer)
+    }
+}
+
+/// Contains all necessary fields to define an asset of a release
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub browser_download_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub label: Option<String>,
+    /// The state of the asset: `uploaded` (or `open` during an upload)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub download_count: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub uploader: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Contains all necessary fields to define a release
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Release {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub assets_url: Option<String>,
+    /// The URL template to upload the assets (`upload_asset` builds it from the uploads URL of
+    /// the client)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub upload_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tarball_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub zipball_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tag_name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub prerelease: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author: Option<UserInfoStructure>,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub published_at: Option<String>,
+}
+
+/// Fields to create a release
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewRelease {
+    /// The name of the tag (created from `target_commitish` if it does not exist)
+    pub tag_name: String,
+    /// The branch or the commit SHA to tag (the default branch if `None`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the release, in Markdown
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    /// Creates an unpublished release
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub prerelease: Option<bool>,
+    /// Generates the name and the notes of the release from the merged pull requests (the
+    /// generated notes are appended to the given body)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub generate_release_notes: Option<bool>,
+}
+
+impl NewRelease {
+    /// Returns the fields to create a release for a tag
+    ///
+    /// # Argument
+    ///
+    /// * `tag_name` - The name of the tag, like `v1.0.0`
+    pub fn new(tag_name: &str) -> Self {
+        NewRelease {
+            tag_name: tag_name.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Fields that can be modified for a given release
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tag_name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    /// `Some(false)` publishes a draft
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub prerelease: Option<bool>,
+}
+
+/// Fields to upload an asset
+#[derive(Debug, Clone)]
+pub struct NewAsset {
+    /// The file name of the asset
+    pub name: String,
+    /// A short description of the asset, shown instead of the file name
+    pub label: Option<String>,
+    /// The media type of the asset, like `application/zip`
+    pub content_type: String,
+}
+
+impl NewAsset {
+    /// Returns the fields to upload an asset
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The file name of the asset
+    /// * `content_type` - The media type of the asset
+    pub fn new(name: &str, content_type: &str) -> Self {
+        NewAsset {
+            name: name.to_owned(),
+            label: None,
+            content_type: content_type.to_owned(),
+        }
+    }
+}
+
+/// Fields that can be modified for a given asset
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub label: Option<String>,
+}
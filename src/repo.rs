@@ -2,14 +2,30 @@
 /// Module to compose with `repos`.
 /// Github documentation available at https://developer.github.com/v3/repos/.
 ///
-use client::GithubClient;
+use branches::BranchesClient;
+use checks::ChecksClient;
+use client::{GithubClient, RequestOptions, encode_segment};
+use commit_builder::CommitBuilder;
+use commits::{CommitsClient, ObjectRef};
+use contents::ContentsClient;
+use git::GitClient;
+use Error;
 use GetterAPI;
 use hyper::method::Method;
+use issue_comments::IssueCommentsClient;
+use issues::IssuesClient;
+use labels::LabelsClient;
+use milestones::MilestonesClient;
+use pagination::{ListOptions, Pages};
+use pulls::PullRequestsClient;
+use releases::ReleasesClient;
 use user::UserInfoStructure;
 use serde_json;
+use statuses::StatusesClient;
+use std::io::Write;
 
 /// Static string that corresponds to the repository URL
-const REPOS_API_URL: &'static str = "repos";
+pub const REPOS_API_URL: &'static str = "repos";
 
 /// Structure that represents a repository client.
 /// This structure is needed to communicate with the Github API, about a repository owned by a Github user.
@@ -41,22 +57,156 @@ impl<'a> RepoClient<'a> {
             reponame: reponame.to_owned(),
         }
     }
+
+    /// Returns a client to communicate with the Github API about the issues of the repository
+    pub fn issues(&self) -> IssuesClient<'a> {
+        IssuesClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the comments of the issues (and
+    /// pull requests) of the repository
+    pub fn issue_comments(&self) -> IssueCommentsClient<'a> {
+        IssueCommentsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the pull requests of the
+    /// repository
+    pub fn pulls(&self) -> PullRequestsClient<'a> {
+        PullRequestsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the files and directories of the
+    /// repository
+    pub fn contents(&self) -> ContentsClient<'a> {
+        ContentsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the git database (blobs, trees,
+    /// commits, references and tags) of the repository
+    pub fn git(&self) -> GitClient<'a> {
+        GitClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the branches of the repository,
+    /// and their protection
+    pub fn branches(&self) -> BranchesClient<'a> {
+        BranchesClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the commits of the repository
+    pub fn commits(&self) -> CommitsClient<'a> {
+        CommitsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the statuses of the commits of
+    /// the repository
+    pub fn statuses(&self) -> StatusesClient<'a> {
+        StatusesClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the check runs and the check
+    /// suites of the repository
+    pub fn checks(&self) -> ChecksClient<'a> {
+        ChecksClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the releases of the repository,
+    /// and their assets
+    pub fn releases(&self) -> ReleasesClient<'a> {
+        ReleasesClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the labels of the repository,
+    /// and of its issues
+    pub fn labels(&self) -> LabelsClient<'a> {
+        LabelsClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns a client to communicate with the Github API about the milestones of the repository
+    pub fn milestones(&self) -> MilestonesClient<'a> {
+        MilestonesClient::new(self.github_client, &self.owner, &self.reponame)
+    }
+
+    /// Returns the tags of the repository
+    ///
+    /// # Argument
+    ///
+    /// * `options` - The pagination parameters
+    pub fn tags(&self, options: &ListOptions) -> Pages<'a, RepoTag> {
+        let url = format!("{}/{}/{}/tags", REPOS_API_URL, self.owner, self.reponame);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a builder to commit a set of file changes (additions, modifications, deletions and
+    /// renames) to a branch, in a single commit
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The branch to commit to, like `master`
+    /// * `message` - The commit message
+    pub fn commit_builder(&self, branch: &str, message: &str) -> CommitBuilder<'a> {
+        CommitBuilder::new(self.git(), branch, message)
+    }
+
+    /// Downloads a tarball (`.tar.gz`) archive of the repository into a writer, and returns the
+    /// number of bytes written
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The branch, tag or commit SHA to archive (the default branch if `None`)
+    /// * `writer` - The destination of the archive
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let mut file = File::create("github-api-rs.tar.gz").unwrap();
+    /// let size = repo_client.tarball(Some("v1.0.0"), &mut file);
+    /// `
+    pub fn tarball<W: Write>(&self, reference: Option<&str>, writer: &mut W) -> Result<u64, Error> {
+        self.archive("tarball", reference, writer)
+    }
+
+    /// Downloads a zipball (`.zip`) archive of the repository into a writer, and returns the
+    /// number of bytes written
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The branch, tag or commit SHA to archive (the default branch if `None`)
+    /// * `writer` - The destination of the archive
+    pub fn zipball<W: Write>(&self, reference: Option<&str>, writer: &mut W) -> Result<u64, Error> {
+        self.archive("zipball", reference, writer)
+    }
+
+    /// Downloads an archive of the repository, following the redirection to the archive
+    fn archive<W: Write>(&self,
+                         format: &str,
+                         reference: Option<&str>,
+                         writer: &mut W)
+                         -> Result<u64, Error> {
+        let url = format!("{}/{}/{}/{}/{}",
+                          REPOS_API_URL,
+                          self.owner,
+                          self.reponame,
+                          format,
+                          encode_segment(reference.unwrap_or("")));
+        self.github_client.download(url.trim_right_matches('/'), &RequestOptions::new(), writer)
+    }
 }
 
 impl<'a> GetterAPI for RepoClient<'a> {
     type GetType = RepoInfoStructure;
     /// Returns a structure to get informations about a repository (RepoInfoStructure), owned by someone
-    fn get(&self) -> Result<RepoInfoStructure, String> {
+    fn get(&self) -> Result<RepoInfoStructure, Error> {
         let url = format!("{}/{}/{}", REPOS_API_URL, self.owner, self.reponame);
         match self.github_client.process_request(Method::Get, &url, None) {
             Ok(response) => {
                 match serde_json::from_str(&response) {
                     Ok(repo_structure) => Ok(repo_structure),
                     Err(error) => {
-                        Err(format!("Error when converting the string request from Github to \
-                                     JSON: {}, for response {}",
-                                    error,
-                                    response))
+                        Err(Error::Deserialize {
+                            error: error,
+                            body: response,
+                        })
                     }
                 }
             }
@@ -65,74 +215,94 @@ impl<'a> GetterAPI for RepoClient<'a> {
     }
 }
 
+/// A tag of a repository, as listed by the tags endpoint
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RepoTag {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    /// The commit the tag points to
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit: Option<ObjectRef>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub zipball_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tarball_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+}
+
 /// Contains all necessary fields to define permissions, for a given repository
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RepoPermissionsStructure {
     #[serde(skip_serializing_if="Option::is_none")]
-    admin: Option<bool>,
+    pub admin: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    push: Option<bool>,
+    pub push: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    pull: Option<bool>,
+    pub pull: Option<bool>,
 }
 
 /// Contains all necessary fields to define a repository
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RepoInfoStructure {
     #[serde(skip_serializing_if="Option::is_none")]
-    id: Option<usize>,
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub owner: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    owner: Option<UserInfoStructure>,
+    pub full_name: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    name: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    description: Option<String>,
+    pub private: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    private: Option<bool>,
+    pub fork: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    fork: Option<bool>,
+    pub url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    url: Option<String>,
+    pub html_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    html_url: Option<String>,
+    pub branches_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    branches_url: Option<String>,
+    pub collaborators_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    collaborators_url: Option<String>,
+    pub contributors_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    contributors_url: Option<String>,
+    pub forks_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    forks_url: Option<String>,
+    pub languages_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    languages_url: Option<String>,
+    pub releases_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    releases_url: Option<String>,
+    pub stargazers_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    stargazers_url: Option<String>,
+    pub subscribers_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    subscribers_url: Option<String>,
+    pub subscription_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    subscription_url: Option<String>,
+    pub homepage: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    homepage: Option<String>,
+    pub language: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    language: Option<String>,
+    pub default_branch: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    forks_count: Option<usize>,
+    pub forks_count: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    stargazers_count: Option<usize>,
+    pub stargazers_count: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    watchers_count: Option<usize>,
+    pub watchers_count: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    size: Option<usize>,
+    pub size: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    open_issues_count: Option<usize>,
+    pub open_issues_count: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    permissions: Option<RepoPermissionsStructure>,
+    pub permissions: Option<RepoPermissionsStructure>,
     #[serde(skip_serializing_if="Option::is_none")]
-    subscribers_count: Option<usize>,
+    pub subscribers_count: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    parent: Option<Box<RepoInfoStructure>>,
+    pub parent: Option<Box<RepoInfoStructure>>,
     #[serde(skip_serializing_if="Option::is_none")]
-    source: Option<Box<RepoInfoStructure>>,
+    pub source: Option<Box<RepoInfoStructure>>,
 }
\ No newline at end of file
@@ -0,0 +1,173 @@
+///
+/// Module to compose with retries.
+/// Github documentation available at
+/// https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits.
+///
+use error::Error;
+use hyper;
+use hyper::header::{Header, HeaderFormat, HttpDate};
+use hyper::method::Method;
+use hyper::status::StatusClass;
+
+use std::cmp;
+use std::fmt;
+use std::str;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Delay to wait after a secondary rate limit, if Github did not send a Retry-After header
+const DEFAULT_RETRY_AFTER: u64 = 60;
+/// Upper bound of the exponential backoff, in milliseconds
+const MAX_BACKOFF: u64 = 60 * 1000;
+
+/// How requests are sent again after a secondary rate limit, a server error (5xx) or a connection
+/// error.
+/// Secondary rate limits are retried after the delay asked by Github (the Retry-After header).
+/// Server and connection errors are retried using an exponential backoff with jitter, except for
+/// POST requests that may have been processed by Github.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to send a request, including the first one
+    pub max_attempts: usize,
+    /// The maximum time to wait between attempts, for a single request
+    pub max_total_wait: Duration,
+    /// The delay before the first retry, doubled for each next retry
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never sends a request again
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            max_total_wait: Duration::from_secs(0),
+            base_delay: Duration::from_secs(0),
+        }
+    }
+
+    /// Returns the delay to wait before sending a request again, or None if the request must not
+    /// be sent again
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP/HTTPS method of the request
+    /// * `attempt` - The number of attempts already made, starting at 1
+    /// * `waited` - The time already waited between the previous attempts
+    /// * `error` - The error of the last attempt
+    pub fn delay(&self,
+                 method: &Method,
+                 attempt: usize,
+                 waited: Duration,
+                 error: &Error)
+                 -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let delay = match *error {
+            Error::SecondaryRateLimitExceeded { retry_after, .. } => {
+                Duration::from_secs(retry_after.unwrap_or(DEFAULT_RETRY_AFTER))
+            }
+            Error::Http(_) if *method != Method::Post => self.backoff(attempt),
+            Error::Api { status, .. } if status.class() == StatusClass::ServerError &&
+                                        *method != Method::Post => {
+                self.backoff(attempt)
+            }
+            _ => return None,
+        };
+        if waited + delay > self.max_total_wait {
+            None
+        } else {
+            Some(delay)
+        }
+    }
+
+    /// Returns a random delay between the half and the whole of `base_delay * 2^(attempt - 1)`
+    ///
+    /// # Argument
+    ///
+    /// * `attempt` - The number of attempts already made, starting at 1
+    fn backoff(&self, attempt: usize) -> Duration {
+        let base = self.base_delay.as_secs() * 1000 +
+                   (self.base_delay.subsec_nanos() / 1000000) as u64;
+        let exponent = cmp::min(attempt.saturating_sub(1), 16) as u32;
+        let backoff = cmp::min(base.saturating_mul(2u64.pow(exponent)), MAX_BACKOFF);
+        let half = backoff / 2;
+        Duration::from_millis(half + jitter(half + 1))
+    }
+}
+
+/// Three attempts, at most one minute of waiting, and one second before the first retry
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            max_total_wait: Duration::from_secs(60),
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// The Retry-After header, sent by Github with a secondary rate limit: a delay in seconds, or the
+/// date after which a request can be sent again
+#[derive(Debug, Clone, PartialEq)]
+pub enum RetryAfter {
+    /// The delay to wait, in seconds
+    Delay(u64),
+    /// The date to wait for
+    DateTime(HttpDate),
+}
+
+impl RetryAfter {
+    /// Returns the delay to wait, in seconds
+    ///
+    /// # Argument
+    ///
+    /// * `now` - The current time, in UTC epoch seconds
+    pub fn delay(&self, now: u64) -> u64 {
+        match *self {
+            RetryAfter::Delay(seconds) => seconds,
+            RetryAfter::DateTime(ref date) => {
+                (cmp::max(date.0.to_timespec().sec, 0) as u64).saturating_sub(now)
+            }
+        }
+    }
+}
+
+impl Header for RetryAfter {
+    fn header_name() -> &'static str {
+        "Retry-After"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> hyper::Result<Self> {
+        if raw.len() != 1 {
+            return Err(hyper::Error::Header);
+        }
+        let value = try!(str::from_utf8(&raw[0]).map_err(|_| hyper::Error::Header)).trim();
+        match value.parse() {
+            Ok(seconds) => Ok(RetryAfter::Delay(seconds)),
+            Err(_) => value.parse().map(RetryAfter::DateTime),
+        }
+    }
+}
+
+impl HeaderFormat for RetryAfter {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RetryAfter::Delay(seconds) => write!(f, "{}", seconds),
+            RetryAfter::DateTime(ref date) => fmt::Display::fmt(date, f),
+        }
+    }
+}
+
+/// Returns a pseudo-random number in `[0, bound)`, good enough to spread retries
+fn jitter(bound: u64) -> u64 {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.subsec_nanos() as u64,
+        Err(_) => 0,
+    };
+    // Scramble the low-entropy bits of the clock (xorshift)
+    let mut value = nanos ^ 0x9E3779B97F4A7C15;
+    value ^= value << 13;
+    value ^= value >> 7;
+    value ^= value << 17;
+    value % bound
+}
@@ -0,0 +1,256 @@
+///
+/// Module to compose with pull request review comments.
+/// Github documentation available at https://developer.github.com/v3/pulls/comments/.
+///
+use client::{GithubClient, append_query, encode};
+use error::Error;
+use hyper::method::Method;
+use issue_comments::{CommentBodyStructure, IssueCommentListOptions};
+use pagination::{ListOptions, Pages};
+use repo::REPOS_API_URL;
+use user::UserInfoStructure;
+
+/// Structure that represents a review comments client.
+/// This structure is needed to communicate with the Github API, about the comments on the diff of
+/// the pull requests of a repository.
+pub struct ReviewCommentsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> ReviewCommentsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the review comments
+    /// of the pull requests of a repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let comments_client = ReviewCommentsClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        ReviewCommentsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the pull requests of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}/pulls", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Returns the review comments of a pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `options` - The pagination parameters
+    pub fn list(&self, number: usize, options: &ListOptions) -> Pages<'a, ReviewComment> {
+        let url = format!("{}/{}/comments", self.url(), number);
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the review comments of all the pull requests of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to apply to the list
+    /// * `options` - The pagination parameters
+    pub fn list_for_repo(&self,
+                         filters: &IssueCommentListOptions,
+                         options: &ListOptions)
+                         -> Pages<'a, ReviewComment> {
+        let url = append_query(&format!("{}/comments", self.url()), &filters.to_params());
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns a review comment
+    ///
+    /// # Argument
+    ///
+    /// * `comment_id` - The identifier of the comment
+    pub fn get(&self, comment_id: usize) -> Result<ReviewComment, Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a review comment on the diff of a pull request, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `comment` - The comment to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let comment = NewReviewComment::new("Typo", "6dcb09b5b57875f334f61aebed695e2e4193db5e", "README.md", 12);
+    /// let comment = comments_client.create(42, &comment);
+    /// `
+    pub fn create(&self, number: usize, comment: &NewReviewComment) -> Result<ReviewComment, Error> {
+        let url = format!("{}/{}/comments", self.url(), number);
+        let body = try!(encode(comment));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Replies to a top-level review comment, and returns the reply
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The number of the pull request
+    /// * `comment_id` - The identifier of the comment to reply to
+    /// * `body` - The content of the reply, in Markdown
+    pub fn reply(&self, number: usize, comment_id: usize, body: &str) -> Result<ReviewComment, Error> {
+        let url = format!("{}/{}/comments/{}/replies", self.url(), number, comment_id);
+        let body = try!(encode(&CommentBodyStructure { body: body.to_owned() }));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Updates a review comment, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The identifier of the comment
+    /// * `body` - The new content of the comment, in Markdown
+    pub fn update(&self, comment_id: usize, body: &str) -> Result<ReviewComment, Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        let body = try!(encode(&CommentBodyStructure { body: body.to_owned() }));
+        self.github_client.request_json(Method::Patch, &url, Some(body))
+    }
+
+    /// Deletes a review comment
+    ///
+    /// # Argument
+    ///
+    /// * `comment_id` - The identifier of the comment
+    pub fn delete(&self, comment_id: usize) -> Result<(), Error> {
+        let url = format!("{}/comments/{}", self.url(), comment_id);
+        self.github_client.process_request(Method::Delete, &url, None).map(|_| ())
+    }
+}
+
+/// The side of the diff that a comment applies to
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Side {
+    /// The deletions (in red)
+    #[serde(rename = "LEFT")]
+    Left,
+    /// The additions (in green) or the unchanged lines
+    #[serde(rename = "RIGHT")]
+    Right,
+}
+
+/// Fields to create a review comment
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewReviewComment {
+    pub body: String,
+    /// The SHA of the commit to comment
+    pub commit_id: String,
+    /// The path of the file to comment
+    pub path: String,
+    /// The line of the diff to comment (the last line, for a multi-line comment)
+    pub line: usize,
+    /// The side of the diff of the line
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub side: Option<Side>,
+    /// The first line of a multi-line comment
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_line: Option<usize>,
+    /// The side of the diff of the first line of a multi-line comment
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_side: Option<Side>,
+}
+
+impl NewReviewComment {
+    /// Returns the fields to comment a single line of a file
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The content of the comment, in Markdown
+    /// * `commit_id` - The SHA of the commit to comment
+    /// * `path` - The path of the file to comment
+    /// * `line` - The line of the diff to comment
+    pub fn new(body: &str, commit_id: &str, path: &str, line: usize) -> Self {
+        NewReviewComment {
+            body: body.to_owned(),
+            commit_id: commit_id.to_owned(),
+            path: path.to_owned(),
+            line: line,
+            ..Default::default()
+        }
+    }
+
+    /// Extends the comment to several lines, from `start_line` to the line of the comment
+    ///
+    /// # Arguments
+    ///
+    /// * `start_line` - The first line of the comment
+    /// * `start_side` - The side of the diff of the first line
+    pub fn start_at(mut self, start_line: usize, start_side: Side) -> Self {
+        self.start_line = Some(start_line);
+        self.start_side = Some(start_side);
+        self
+    }
+}
+
+/// Contains all necessary fields to define a comment on the diff of a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReviewComment {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pull_request_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pull_request_review_id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub in_reply_to_id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub diff_hunk: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub position: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub original_position: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub original_commit_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub original_line: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub side: Option<Side>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_line: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub original_start_line: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_side: Option<Side>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_association: Option<String>,
+}
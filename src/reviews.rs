@@ -0,0 +1,420 @@
+///
+/// Module to compose with pull request reviews, and requested reviewers.
+/// Github documentation available at https://developer.github.com/v3/pulls/reviews/ and
+/// https://developer.github.com/v3/pulls/review_requests/.
+///
+use client::{GithubClient, encode};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use pulls::PullRequest;
+use repo::REPOS_API_URL;
+use review_comments::{ReviewComment, Side};
+use user::UserInfoStructure;
+
+/// Structure that represents a reviews client.
+/// This structure is needed to communicate with the Github API, about the reviews of a pull
+/// request.
+pub struct ReviewsClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+    /// Number of the pull request
+    number: usize,
+}
+
+impl<'a> ReviewsClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the reviews of a pull
+    /// request
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    /// * `number` - The number of the pull request
+    ///
+    /// # Example
+    ///
+    /// `let reviews_client = ReviewsClient::new(&github_client, "k0pernicus", "github-api-rs", 42);`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str, number: usize) -> Self {
+        ReviewsClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+            number: number,
+        }
+    }
+
+    /// Returns the URL of the reviews of the pull request
+    fn url(&self) -> String {
+        format!("{}/{}/{}/pulls/{}/reviews",
+                REPOS_API_URL,
+                self.owner,
+                self.reponame,
+                self.number)
+    }
+
+    /// Returns the reviews of the pull request
+    ///
+    /// # Argument
+    ///
+    /// * `options` - The pagination parameters
+    pub fn list(&self, options: &ListOptions) -> Pages<'a, Review> {
+        self.github_client.get_pages(&self.url(), options)
+    }
+
+    /// Returns a review of the pull request
+    ///
+    /// # Argument
+    ///
+    /// * `review_id` - The identifier of the review
+    pub fn get(&self, review_id: usize) -> Result<Review, Error> {
+        let url = format!("{}/{}", self.url(), review_id);
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+
+    /// Creates a review, and returns it.
+    /// The review stays pending until it is submitted, if no event is given.
+    ///
+    /// # Argument
+    ///
+    /// * `review` - The review to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let review = NewReview { body: Some(String::from("LGTM")), event: Some(ReviewEvent::Approve), ..Default::default() };
+    /// let review = reviews_client.create(&review);
+    /// `
+    pub fn create(&self, review: &NewReview) -> Result<Review, Error> {
+        let body = try!(encode(review));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Updates the body of a review, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `review_id` - The identifier of the review
+    /// * `body` - The new body of the review
+    pub fn update(&self, review_id: usize, body: &str) -> Result<Review, Error> {
+        let url = format!("{}/{}", self.url(), review_id);
+        let body = try!(encode(&ReviewBodyStructure {
+            body: Some(body.to_owned()),
+            event: None,
+            message: None,
+        }));
+        self.github_client.request_json(Method::Put, &url, Some(body))
+    }
+
+    /// Submits a pending review, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `review_id` - The identifier of the pending review
+    /// * `event` - The action of the review
+    /// * `body` - The body of the review, if any (required to request changes or to comment)
+    pub fn submit(&self,
+                  review_id: usize,
+                  event: ReviewEvent,
+                  body: Option<&str>)
+                  -> Result<Review, Error> {
+        let url = format!("{}/{}/events", self.url(), review_id);
+        let body = try!(encode(&ReviewBodyStructure {
+            body: body.map(|body| body.to_owned()),
+            event: Some(event.as_str()),
+            message: None,
+        }));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Dismisses a submitted review, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `review_id` - The identifier of the review
+    /// * `message` - The reason of the dismissal
+    pub fn dismiss(&self, review_id: usize, message: &str) -> Result<Review, Error> {
+        let url = format!("{}/{}/dismissals", self.url(), review_id);
+        let body = try!(encode(&ReviewBodyStructure {
+            body: None,
+            event: Some("DISMISS"),
+            message: Some(message.to_owned()),
+        }));
+        self.github_client.request_json(Method::Put, &url, Some(body))
+    }
+
+    /// Deletes a pending review, and returns it
+    ///
+    /// # Argument
+    ///
+    /// * `review_id` - The identifier of the pending review
+    pub fn delete_pending(&self, review_id: usize) -> Result<Review, Error> {
+        let url = format!("{}/{}", self.url(), review_id);
+        self.github_client.request_json(Method::Delete, &url, None)
+    }
+
+    /// Returns the comments of a review
+    ///
+    /// # Arguments
+    ///
+    /// * `review_id` - The identifier of the review
+    /// * `options` - The pagination parameters
+    pub fn comments(&self, review_id: usize, options: &ListOptions) -> Pages<'a, ReviewComment> {
+        let url = format!("{}/{}/comments", self.url(), review_id);
+        self.github_client.get_pages(&url, options)
+    }
+}
+
+/// Structure that represents a requested reviewers client.
+/// This structure is needed to communicate with the Github API, about the users and teams
+/// requested to review a pull request.
+pub struct RequestedReviewersClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+    /// Number of the pull request
+    number: usize,
+}
+
+impl<'a> RequestedReviewersClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the requested
+    /// reviewers of a pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    /// * `number` - The number of the pull request
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str, number: usize) -> Self {
+        RequestedReviewersClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+            number: number,
+        }
+    }
+
+    /// Returns the URL of the requested reviewers of the pull request
+    fn url(&self) -> String {
+        format!("{}/{}/{}/pulls/{}/requested_reviewers",
+                REPOS_API_URL,
+                self.owner,
+                self.reponame,
+                self.number)
+    }
+
+    /// Returns the users and the teams requested to review the pull request
+    pub fn list(&self) -> Result<RequestedReviewers, Error> {
+        self.github_client.request_json(Method::Get, &self.url(), None)
+    }
+
+    /// Requests users and teams to review the pull request, and returns the pull request
+    ///
+    /// # Argument
+    ///
+    /// * `reviewers` - The logins of the users, and the slugs of the teams
+    ///
+    /// # Example
+    ///
+    /// `let pull_request = reviewers_client.request(&ReviewersRequest::users(&["octocat"]));`
+    pub fn request(&self, reviewers: &ReviewersRequest) -> Result<PullRequest, Error> {
+        let body = try!(encode(reviewers));
+        self.github_client.request_json(Method::Post, &self.url(), Some(body))
+    }
+
+    /// Removes review requests of users and teams, and returns the pull request
+    ///
+    /// # Argument
+    ///
+    /// * `reviewers` - The logins of the users, and the slugs of the teams
+    pub fn remove(&self, reviewers: &ReviewersRequest) -> Result<PullRequest, Error> {
+        let body = try!(encode(reviewers));
+        self.github_client.request_json(Method::Delete, &self.url(), Some(body))
+    }
+}
+
+/// The action performed by a review
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ReviewEvent {
+    #[serde(rename = "APPROVE")]
+    Approve,
+    #[serde(rename = "REQUEST_CHANGES")]
+    RequestChanges,
+    #[serde(rename = "COMMENT")]
+    Comment,
+}
+
+impl ReviewEvent {
+    /// Returns the value of the `event` field
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ReviewEvent::Approve => "APPROVE",
+            ReviewEvent::RequestChanges => "REQUEST_CHANGES",
+            ReviewEvent::Comment => "COMMENT",
+        }
+    }
+}
+
+/// The state of a review
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ReviewState {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "APPROVED")]
+    Approved,
+    #[serde(rename = "CHANGES_REQUESTED")]
+    ChangesRequested,
+    #[serde(rename = "COMMENTED")]
+    Commented,
+    #[serde(rename = "DISMISSED")]
+    Dismissed,
+}
+
+/// Contains all necessary fields to define a review of a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Review {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<ReviewState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pull_request_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub submitted_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_association: Option<String>,
+}
+
+/// A comment on the diff, created along with a review
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DraftReviewComment {
+    /// The path of the file to comment
+    pub path: String,
+    pub body: String,
+    /// The line of the diff to comment (the last line, for a multi-line comment)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub line: Option<usize>,
+    /// The side of the diff of the line
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub side: Option<Side>,
+    /// The first line of a multi-line comment
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_line: Option<usize>,
+    /// The side of the diff of the first line of a multi-line comment
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub start_side: Option<Side>,
+}
+
+/// Fields to create a review
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewReview {
+    /// The SHA of the commit to review (the last commit of the pull request by default)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub body: Option<String>,
+    /// The action of the review: the review is pending without action
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub event: Option<ReviewEvent>,
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub comments: Vec<DraftReviewComment>,
+}
+
+/// Structure sent to update, submit or dismiss a review
+#[derive(Serialize)]
+struct ReviewBodyStructure {
+    #[serde(skip_serializing_if="Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    event: Option<&'static str>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    message: Option<String>,
+}
+
+/// Contains all necessary fields to define a team
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Team {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub privacy: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub permission: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub html_url: Option<String>,
+}
+
+/// The users and the teams requested to review a pull request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestedReviewers {
+    #[serde(default)]
+    pub users: Vec<UserInfoStructure>,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+}
+
+/// Users and teams to request (or to remove) as reviewers of a pull request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewersRequest {
+    /// The logins of the users
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub reviewers: Vec<String>,
+    /// The slugs of the teams
+    #[serde(default, skip_serializing_if="Vec::is_empty")]
+    pub team_reviewers: Vec<String>,
+}
+
+impl ReviewersRequest {
+    /// Returns a request for some users
+    ///
+    /// # Argument
+    ///
+    /// * `logins` - The logins of the users
+    pub fn users(logins: &[&str]) -> Self {
+        ReviewersRequest {
+            reviewers: logins.iter().map(|login| (*login).to_owned()).collect(),
+            team_reviewers: Vec::new(),
+        }
+    }
+
+    /// Returns a request for some teams
+    ///
+    /// # Argument
+    ///
+    /// * `slugs` - The slugs of the teams
+    pub fn teams(slugs: &[&str]) -> Self {
+        ReviewersRequest {
+            reviewers: Vec::new(),
+            team_reviewers: slugs.iter().map(|slug| (*slug).to_owned()).collect(),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+///
+/// Module to compose with commit statuses.
+/// Github documentation available at https://developer.github.com/v3/repos/statuses/.
+///
+use client::{GithubClient, encode, encode_segment};
+use error::Error;
+use hyper::method::Method;
+use pagination::{ListOptions, Pages};
+use repo::{REPOS_API_URL, RepoInfoStructure};
+use user::UserInfoStructure;
+
+/// Structure that represents a statuses client.
+/// This structure is needed to communicate with the Github API, about the statuses of the commits
+/// of a repository.
+pub struct StatusesClient<'a> {
+    /// The Github client
+    github_client: &'a GithubClient,
+    /// Owner of the repository
+    owner: String,
+    /// Repository name
+    reponame: String,
+}
+
+impl<'a> StatusesClient<'a> {
+    /// Returns a client to communicate with the Github API, specifically for the statuses of the
+    /// commits of a repository
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `owner` - The owner of the repository
+    /// * `reponame` - The repository name
+    ///
+    /// # Example
+    ///
+    /// `let statuses_client = StatusesClient::new(&github_client, "k0pernicus", "github-api-rs");`
+    pub fn new(github_client: &'a GithubClient, owner: &str, reponame: &str) -> Self {
+        StatusesClient {
+            github_client: github_client,
+            owner: owner.to_owned(),
+            reponame: reponame.to_owned(),
+        }
+    }
+
+    /// Returns the URL of the repository
+    fn url(&self) -> String {
+        format!("{}/{}/{}", REPOS_API_URL, self.owner, self.reponame)
+    }
+
+    /// Creates a status for a commit, and returns it
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - The SHA of the commit
+    /// * `status` - The status to create
+    ///
+    /// # Example
+    ///
+    /// `
+    /// let status = NewStatus::new(StatusState::Success, "ci/build").description("The build succeeded");
+    /// let status = statuses_client.create("6dcb09b5b57875f334f61aebed695e2e4193db5e", &status);
+    /// `
+    pub fn create(&self, sha: &str, status: &NewStatus) -> Result<Status, Error> {
+        let url = format!("{}/statuses/{}", self.url(), sha);
+        let body = try!(encode(status));
+        self.github_client.request_json(Method::Post, &url, Some(body))
+    }
+
+    /// Returns the statuses of a commit, the most recent first
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    /// * `options` - The pagination parameters
+    pub fn list(&self, reference: &str, options: &ListOptions) -> Pages<'a, Status> {
+        let url = format!("{}/commits/{}/statuses", self.url(), encode_segment(reference));
+        self.github_client.get_pages(&url, options)
+    }
+
+    /// Returns the combined status of a commit: the latest status of each context, and the
+    /// resulting state
+    ///
+    /// # Argument
+    ///
+    /// * `reference` - The SHA of the commit, or the name of a branch or of a tag
+    pub fn combined(&self, reference: &str) -> Result<CombinedStatus, Error> {
+        let url = format!("{}/commits/{}/status", self.url(), encode_segment(reference));
+        self.github_client.request_json(Method::Get, &url, None)
+    }
+}
+
+/// The state of a status of a commit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StatusState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "success")]
+    Success,
+}
+
+/// Fields to create a status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewStatus {
+    pub state: StatusState,
+    /// The URL of the details of the status, like the log of the build
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_url: Option<String>,
+    /// A short description of the status
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    /// The label that identifies the system that sets the status (`default` if `None`)
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub context: Option<String>,
+}
+
+impl NewStatus {
+    /// Returns the fields to create a status
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state of the status
+    /// * `context` - The label that identifies the system that sets the status, like `ci/build`
+    pub fn new(state: StatusState, context: &str) -> Self {
+        NewStatus {
+            state: state,
+            target_url: None,
+            description: None,
+            context: Some(context.to_owned()),
+        }
+    }
+
+    /// Sets the URL of the details of the status
+    pub fn target_url(mut self, target_url: &str) -> Self {
+        self.target_url = Some(target_url.to_owned());
+        self
+    }
+
+    /// Sets the description of the status
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+}
+
+/// Contains all necessary fields to define a status of a commit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Status {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<StatusState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub target_url: Option<String>,
+    /// The label that identifies the system that sets the status, like `ci/build`
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub context: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub creator: Option<UserInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Contains all necessary fields to define the combined status of a commit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CombinedStatus {
+    /// The combined state: `Failure` if any status is an error or a failure, `Pending` if there
+    /// is no status or if any status is pending, `Success` otherwise
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub state: Option<StatusState>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_count: Option<usize>,
+    /// The latest status of each context
+    #[serde(default)]
+    pub statuses: Vec<Status>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub repository: Option<RepoInfoStructure>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub commit_url: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub url: Option<String>,
+}
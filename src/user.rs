@@ -3,13 +3,14 @@
 /// Github documentation available at https://developer.github.com/v3/users/.
 ///
 use client::GithubClient;
+use Error;
 use GetterAPI;
 use hyper::method::Method;
 use serde_json;
 use UpdaterAPI;
 
 /// URL to access the Github API for myself
-const USER_API_URL: &'static str = "user";
+pub const USER_API_URL: &'static str = "user";
 /// URL to access the Github API for other Github users
 const USERS_API_URL: &'static str = "users";
 
@@ -20,6 +21,8 @@ pub struct UserClient<'a> {
     github_client: &'a GithubClient,
     /// The username to build requests
     pub username: String,
+    /// Is the user the authenticated one
+    is_myself: bool,
 }
 
 impl<'a> UserClient<'a> {
@@ -33,28 +36,44 @@ impl<'a> UserClient<'a> {
     /// # Example
     ///
     /// `let user_client = UserClient::new(&github_client, "k0pernicus2");`
-    pub fn new(github_client: &'a GithubClient, username: &'a str) -> Self {
+    pub fn new(github_client: &'a GithubClient, username: &str) -> Self {
+        let is_myself = github_client.username.as_ref().map_or(false, |login| login == username);
         UserClient {
             github_client: github_client,
             username: username.to_owned(),
+            is_myself: is_myself,
+        }
+    }
+
+    /// Returns a client to communicate with the Github API, specifically for the authenticated user
+    ///
+    /// # Arguments
+    ///
+    /// * `github_client` - The Github client that communicate with the Github API
+    /// * `login` - The login of the authenticated user
+    pub fn myself(github_client: &'a GithubClient, login: &str) -> Self {
+        UserClient {
+            github_client: github_client,
+            username: login.to_owned(),
+            is_myself: true,
         }
     }
 }
 
 impl<'a> GetterAPI for UserClient<'a> {
     type GetType = UserInfoStructure;
-    /// Returns a Result type that contains a UserInfoStructure structure, or a String error.
+    /// Returns a Result type that contains a UserInfoStructure structure, or an Error.
     ///
     /// # Example
     ///
     /// `
     /// let github_client = GithubClient::new("k0pernicus", "myapikey0123456789");
-    /// let user_client = github_client.get_myself_client();
-    /// let user_infos = user_client.unwrap();
+    /// let user_client = github_client.get_myself_client().unwrap();
+    /// let user_infos = user_client.get().unwrap();
     /// `
-    fn get(&self) -> Result<UserInfoStructure, String> {
+    fn get(&self) -> Result<UserInfoStructure, Error> {
         /// Check if the current user is the same for this client
-        let url = if self.username == self.github_client.username {
+        let url = if self.is_myself {
             USER_API_URL.to_string()
         } else {
             format!("{}/{}", USERS_API_URL, self.username)
@@ -65,10 +84,10 @@ impl<'a> GetterAPI for UserClient<'a> {
                 match serde_json::from_str(&response) {
                     Ok(user_structure) => Ok(user_structure),
                     Err(error) => {
-                        Err(format!("Error when converting the string request from Github to \
-                                     JSON: {}, for response {}",
-                                    error,
-                                    response))
+                        Err(Error::Deserialize {
+                            error: error,
+                            body: response,
+                        })
                     }
                 }
             }
@@ -85,14 +104,11 @@ impl<'a> UpdaterAPI for UserClient<'a> {
     /// # Argument
     ///
     /// `new_infos` - A UserUpdateStructure that contains some informations to update
-    fn patch(&self, new_infos: &UserUpdateStructure) -> Result<String, String> {
+    fn patch(&self, new_infos: &UserUpdateStructure) -> Result<String, Error> {
         let infos_to_send = serde_json::to_string::<UserUpdateStructure>(new_infos);
         match infos_to_send {
             Ok(body) => self.github_client.process_request(Method::Patch, USER_API_URL, Some(body)),
-            Err(error) => {
-                Err(format!("Error converting the updated structure to string, due to {}",
-                            error))
-            }
+            Err(error) => Err(Error::Serialize(error)),
         }
     }
 }
@@ -101,82 +117,82 @@ impl<'a> UpdaterAPI for UserClient<'a> {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoStructure {
     #[serde(skip_serializing_if="Option::is_none")]
-    login: Option<String>,
+    pub login: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    id: Option<usize>,
+    pub id: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    avatar_url: Option<String>,
+    pub avatar_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    gravatar_id: Option<String>,
+    pub gravatar_id: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    html_url: Option<String>,
+    pub html_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    followers_url: Option<String>,
+    pub followers_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    following_url: Option<String>,
+    pub following_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    gists_url: Option<String>,
+    pub gists_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    starred_url: Option<String>,
+    pub starred_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    subscriptions_url: Option<String>,
+    pub subscriptions_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    organizations_url: Option<String>,
+    pub organizations_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    repos_url: Option<String>,
+    pub repos_url: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    site_admin: Option<bool>,
+    pub site_admin: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    email: Option<String>,
+    pub email: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    public_repos: Option<usize>,
+    pub public_repos: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    public_gists: Option<usize>,
+    pub public_gists: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    followers: Option<usize>,
+    pub followers: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    following: Option<usize>,
+    pub following: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    company: Option<String>,
+    pub company: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    location: Option<String>,
+    pub location: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    hireable: Option<bool>,
+    pub hireable: Option<bool>,
     #[serde(skip_serializing_if="Option::is_none")]
-    bio: Option<String>,
+    pub bio: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    total_private_repos: Option<usize>,
+    pub total_private_repos: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    owned_private_repos: Option<usize>,
+    pub owned_private_repos: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    private_gists: Option<usize>,
+    pub private_gists: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    disk_usage: Option<usize>,
+    pub disk_usage: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    collaborators: Option<usize>,
+    pub collaborators: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    plan: Option<UserPlanStructure>,
+    pub plan: Option<UserPlanStructure>,
 }
 
 /// Fields to constitute a user plan, for the Github API
 #[derive(Serialize, Deserialize, Debug)]
-struct UserPlanStructure {
+pub struct UserPlanStructure {
     #[serde(skip_serializing_if="Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if="Option::is_none")]
-    space: Option<usize>,
+    pub space: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    private_repos: Option<usize>,
+    pub private_repos: Option<usize>,
     #[serde(skip_serializing_if="Option::is_none")]
-    collaborators: Option<usize>,
+    pub collaborators: Option<usize>,
 }
 
 /// Fields that can be modified using the Github API, for a given user
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UserUpdateStructure {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,